@@ -0,0 +1,182 @@
+use std::hint::black_box;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use splitting_of_work::{default_max_threads, split_comp_work, split_comp_work_shared, SplitConfig, WorkPool};
+
+// Lengths of vectors around and above the threshold
+const LENGTHS: [usize; 6] = [4, 8, 16, 64, 256, 1024];
+
+// Number of items for comparing ways of passing the input
+const NUM_OF_ITEMS_FOR_COMPARISON: i64 = 4_000_000;
+
+// Number of strings for measuring the work on heap-allocated items
+const NUM_OF_STRINGS: usize = 10_000;
+
+// Number of items for comparing ways of sending the results
+const NUM_OF_ITEMS_FOR_SENDING: i64 = 100_000;
+
+// Number of threads for comparing ways of sending the results
+const NUM_OF_THREADS_FOR_SENDING: usize = 8;
+
+// Number of items for comparing the pool with spawning of threads per call
+const NUM_OF_ITEMS_FOR_POOL: u64 = 1000;
+
+// Number of threads for comparing the pool with spawning of threads per call
+const NUM_OF_THREADS_FOR_POOL: usize = 4;
+
+// Cheap work, just like in the demo
+fn is_even(num: i64) -> bool {
+    num % 2 == 0
+}
+
+// Expensive work dominated by waiting
+fn slow_is_even(num: i64) -> bool {
+    thread::sleep(Duration::from_micros(20));
+    num % 2 == 0
+}
+
+// Comparing the work in the current thread with the work in threads for every length
+fn compare_paths(c: &mut Criterion, name: &str, function: fn(i64) -> bool) {
+    let sequential = SplitConfig::builder().max_threads(1).build().unwrap();
+    
+    // Threads are spawned for any length, so the crossover point can be seen
+    let threaded = SplitConfig::builder()
+        .threshold(1)
+        .max_threads(default_max_threads().max(2))
+        .build()
+        .unwrap();
+    
+    let mut group = c.benchmark_group(name);
+    
+    for len in LENGTHS {
+        let vector: Vec<i64> = (0..len as i64).collect();
+        
+        group.bench_with_input(BenchmarkId::new("current_thread", len), &vector, |b, vector| {
+            b.iter(|| sequential.run(black_box(vector.clone()), function))
+        });
+        
+        group.bench_with_input(BenchmarkId::new("threads", len), &vector, |b, vector| {
+            b.iter(|| threaded.run(black_box(vector.clone()), function))
+        });
+        
+        // Default configuration with `THRESHOLD` picks the path on its own
+        group.bench_with_input(BenchmarkId::new("split_comp_work", len), &vector, |b, vector| {
+            b.iter(|| split_comp_work(black_box(vector.clone()), function))
+        });
+    }
+    
+    group.finish();
+}
+
+fn cheap_work(c: &mut Criterion) {
+    compare_paths(c, "cheap_work", is_even);
+}
+
+fn expensive_work(c: &mut Criterion) {
+    compare_paths(c, "expensive_work", slow_is_even);
+}
+
+// Comparing the input moved into chunks with the input shared between threads
+fn moved_and_shared_input(c: &mut Criterion) {
+    let vector: Vec<i64> = (0..NUM_OF_ITEMS_FOR_COMPARISON).collect();
+    let data: Arc<[i64]> = Arc::from(vector.clone());
+    
+    let mut group = c.benchmark_group("moved_and_shared_input");
+    
+    // Cloning of the input isn't measured, the moved input is prepared for every iteration
+    group.bench_function("moved", |b| {
+        b.iter_batched(|| vector.clone(), |vector| split_comp_work(vector, is_even), BatchSize::LargeInput)
+    });
+    
+    group.bench_function("shared", |b| {
+        b.iter(|| split_comp_work_shared(Arc::clone(&data), |num: &i64| is_even(*num)))
+    });
+    
+    group.finish();
+}
+
+// Comparing cloning of strings with the work on strings moved into chunks without cloning
+fn string_input(c: &mut Criterion) {
+    let vector: Vec<String> = (0..NUM_OF_STRINGS).map(|num| format!("item {}", num)).collect();
+    
+    let mut group = c.benchmark_group("string_input");
+    
+    // Cloning the input only to show how much it would cost
+    group.bench_function("clone", |b| b.iter(|| black_box(&vector).clone()));
+    
+    group.bench_function("moved", |b| {
+        b.iter_batched(|| vector.clone(), |vector| split_comp_work(vector, |item: String| item.len()),
+            BatchSize::LargeInput)
+    });
+    
+    group.finish();
+}
+
+// Comparing results sent by whole chunks with results sent item by item, which is how the
+// work with reporting of progress sends them
+fn chunk_and_item_sending(c: &mut Criterion) {
+    let config = SplitConfig::builder()
+        .max_threads(NUM_OF_THREADS_FOR_SENDING)
+        .build()
+        .unwrap();
+    
+    let vector: Vec<i64> = (0..NUM_OF_ITEMS_FOR_SENDING).collect();
+    
+    let mut group = c.benchmark_group("chunk_and_item_sending");
+    
+    group.bench_function("chunks", |b| {
+        b.iter_batched(|| vector.clone(), |vector| config.run(vector, is_even), BatchSize::LargeInput)
+    });
+    
+    group.bench_function("items", |b| {
+        b.iter_batched(|| vector.clone(), |vector| config.run_with_progress(vector, is_even, |_, _| {}),
+            BatchSize::LargeInput)
+    });
+    
+    group.finish();
+}
+
+// Comparing threads of the pool reused by every call with threads spawned for every call
+fn pool_and_spawning(c: &mut Criterion) {
+    let pool = WorkPool::new(NUM_OF_THREADS_FOR_POOL);
+    let config = SplitConfig::builder()
+        .max_threads(NUM_OF_THREADS_FOR_POOL)
+        .build()
+        .unwrap();
+    
+    let vector: Vec<u64> = (0..NUM_OF_ITEMS_FOR_POOL).collect();
+    
+    let mut group = c.benchmark_group("pool_and_spawning");
+    
+    group.bench_function("pool", |b| {
+        b.iter_batched(|| vector.clone(), |vector| pool.map(vector, |num: u64| num * 2), BatchSize::SmallInput)
+    });
+    
+    group.bench_function("spawning", |b| {
+        b.iter_batched(|| vector.clone(), |vector| config.run(vector, |num: u64| num * 2), BatchSize::SmallInput)
+    });
+    
+    group.finish();
+}
+
+// Measuring the work on single-element vectors, which are mapped without any splitting
+fn singleton_input(c: &mut Criterion) {
+    c.bench_function("singleton_input", |b| b.iter(|| split_comp_work(vec![black_box(1)], is_even)));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20).measurement_time(Duration::from_secs(2));
+    targets = cheap_work, expensive_work, pool_and_spawning
+}
+
+// Measurements on large inputs, where every iteration takes much longer
+criterion_group! {
+    name = input_benches;
+    config = Criterion::default().sample_size(10).measurement_time(Duration::from_secs(5));
+    targets = moved_and_shared_input, string_input, chunk_and_item_sending, singleton_input
+}
+criterion_main!(benches, input_benches);
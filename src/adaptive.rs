@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::SplitConfig;
+
+// Number of items processed in the current thread to estimate the cost of one item
+const SAMPLE_SIZE: usize = 4;
+
+/// Splits computational work between threads choosing the threshold from the measured cost
+/// of the function.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the first few items are
+/// processed in the current thread first and timed. The threshold is then chosen so that
+/// every chunk of the remaining items takes at least `target` (for example 100 µs), so
+/// cheap functions stay in the current thread and expensive ones are spread over as many
+/// threads as allowed. The estimate is only as good as the timed items are typical of the
+/// whole vector.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use splitting_of_work::split_comp_work_adaptive;
+///
+/// let result = split_comp_work_adaptive((1..=20).collect(), |num: i64| num * 2, Duration::from_micros(100));
+///
+/// assert_eq!(result, (1..=20).map(|num| num * 2).collect::<Vec<i64>>());
+/// ```
+pub fn split_comp_work_adaptive<T, R, F>(vector: Vec<T>, function: F, target: Duration) -> Vec<R>
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    split_comp_work_adaptive_with_config(vector, function, target, &SplitConfig::default())
+}
+
+// Splitting of computational work with the threshold chosen from the measured cost using
+// the configuration, its threshold is replaced and the other settings are kept
+pub(crate) fn split_comp_work_adaptive_with_config<T, R, F>(vector: Vec<T>, function: F, target: Duration,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    let len = vector.len();
+    let mut items = vector.into_iter();
+    
+    // Timing the first items in the current thread, their results are kept
+    let start = Instant::now();
+    let mut results: Vec<R> = items.by_ref().take(SAMPLE_SIZE).map(&function).collect();
+    let elapsed = start.elapsed();
+    
+    if items.len() == 0 {
+        return results;
+    }
+    
+    let threshold = adaptive_threshold(elapsed, results.len(), target);
+    
+    // Logging debugging information
+    debug!("Measured {:?} for {} items, using threshold of {} items", elapsed, results.len(), threshold);
+    
+    let rest: Vec<T> = items.collect();
+    let rest_len = rest.len();
+    let chunk_results = run_in_chunks(rest, &config.with_threshold(threshold), |_, chunk: Vec<T>| {
+        chunk.into_iter().map(&function).collect::<Vec<R>>()
+    });
+    
+    results.reserve_exact(len - results.len());
+    results.extend(concat_in_order(chunk_results, rest_len));
+    
+    results
+}
+
+// Number of items which take at least the target duration, at least one item
+fn adaptive_threshold(elapsed: Duration, num_of_items: usize, target: Duration) -> usize {
+    let per_item = elapsed.as_nanos() / num_of_items as u128;
+    
+    // Items too cheap to be measured are never worth a thread
+    if per_item == 0 {
+        return usize::MAX;
+    }
+    
+    usize::try_from(target.as_nanos().div_ceil(per_item)).unwrap_or(usize::MAX).max(1)
+}
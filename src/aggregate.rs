@@ -0,0 +1,125 @@
+use std::iter::Sum;
+
+use crate::scoped::run_in_chunks;
+use crate::SplitConfig;
+
+/// Sums the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work), every
+/// thread sums its chunk and the partial sums are summed in order of chunks.
+///
+/// The items are added in another grouping than by [`Iterator::sum`], so the overflow
+/// behavior differs from it and depends on how the vector is split. For example
+/// `[-1, 0, i64::MAX, 1]` sums to `i64::MAX` with [`Iterator::sum`], but split as `[-1, 0]`
+/// and `[i64::MAX, 1]` the second partial sum overflows, which panics in debug builds and
+/// wraps in release builds. If partial sums can overflow, the items should be converted to
+/// a wider type first. Sums of floats can differ in rounding for the same reason.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_sum;
+///
+/// assert_eq!(split_comp_sum((1..=100).collect::<Vec<i64>>()), 5050);
+/// ```
+pub fn split_comp_sum<T>(vector: Vec<T>) -> T
+    where T: Sum + Send {
+    split_comp_sum_with_config(vector, &SplitConfig::default())
+}
+
+// Summing of the vector in parallel using the configuration
+pub(crate) fn split_comp_sum_with_config<T>(vector: Vec<T>, config: &SplitConfig) -> T
+    where T: Sum + Send {
+    run_in_chunks(vector, config, |_, chunk: Vec<T>| chunk.into_iter().sum::<T>())
+        .into_iter()
+        .sum()
+}
+
+/// Finds the minimum of the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work), every
+/// thread finds the minimum of its chunk and the minimums are compared in order of chunks.
+/// If several items are equally minimum, the first one is returned. `None` is returned
+/// for an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_min;
+///
+/// assert_eq!(split_comp_min(vec![3, 1, 2]), Some(1));
+/// assert_eq!(split_comp_min(Vec::<i64>::new()), None);
+/// ```
+pub fn split_comp_min<T>(vector: Vec<T>) -> Option<T>
+    where T: Ord + Send {
+    split_comp_min_with_config(vector, &SplitConfig::default())
+}
+
+// Finding of the minimum of the vector in parallel using the configuration
+pub(crate) fn split_comp_min_with_config<T>(vector: Vec<T>, config: &SplitConfig) -> Option<T>
+    where T: Ord + Send {
+    
+    // The earlier item is kept on ties
+    let first_min = |a: T, b: T| if b < a { b } else { a };
+    
+    run_in_chunks(vector, config, |_, chunk: Vec<T>| chunk.into_iter().reduce(first_min))
+        .into_iter()
+        .flatten()
+        .reduce(first_min)
+}
+
+/// Finds the maximum of the vector in parallel.
+///
+/// Works like [`split_comp_min`], but finds the maximum. If several items are equally
+/// maximum, the first one is returned, unlike [`Iterator::max`].
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_max;
+///
+/// assert_eq!(split_comp_max(vec![3, 1, 2]), Some(3));
+/// ```
+pub fn split_comp_max<T>(vector: Vec<T>) -> Option<T>
+    where T: Ord + Send {
+    split_comp_max_with_config(vector, &SplitConfig::default())
+}
+
+// Finding of the maximum of the vector in parallel using the configuration
+pub(crate) fn split_comp_max_with_config<T>(vector: Vec<T>, config: &SplitConfig) -> Option<T>
+    where T: Ord + Send {
+    
+    // The earlier item is kept on ties
+    let first_max = |a: T, b: T| if b > a { b } else { a };
+    
+    run_in_chunks(vector, config, |_, chunk: Vec<T>| chunk.into_iter().reduce(first_max))
+        .into_iter()
+        .flatten()
+        .reduce(first_max)
+}
+
+/// Counts items of the vector satisfying the predicate in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work), every
+/// thread counts the matches in its chunk and only the counts of the chunks are summed,
+/// so no result is kept per item.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_count;
+///
+/// assert_eq!(split_comp_count((1..=100).collect(), |num: &i64| num % 3 == 0), 33);
+/// ```
+pub fn split_comp_count<T, P>(vector: Vec<T>, predicate: P) -> usize
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_count_with_config(vector, predicate, &SplitConfig::default())
+}
+
+// Counting of items satisfying the predicate in parallel using the configuration
+pub(crate) fn split_comp_count_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig) -> usize
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    run_in_chunks(vector, config, |_, chunk: Vec<T>| chunk.iter().filter(|item| predicate(item)).count())
+        .into_iter()
+        .sum()
+}
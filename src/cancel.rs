@@ -0,0 +1,111 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{split_comp_work_in_threads, SplitConfig};
+
+/// Token for cancelling computational work.
+///
+/// Clones of the token share the same flag, so the work can be cancelled from any thread
+/// holding a clone. Once cancelled, the token stays cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::CancelToken;
+///
+/// let token = CancelToken::new();
+/// let token_copy = token.clone();
+///
+/// token_copy.cancel();
+///
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    
+    /// Creates a token which isn't cancelled
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+    
+    /// Cancels the work using this token or any of its clones
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+    
+    /// Returns `true` if the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl From<Arc<AtomicBool>> for CancelToken {
+    
+    /// Wraps an existing stop flag, so setting the flag cancels the work and cancelling the
+    /// token sets the flag
+    fn from(cancelled: Arc<AtomicBool>) -> CancelToken {
+        CancelToken { cancelled }
+    }
+}
+
+/// Splits computational work between threads and allows to cancel it.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but threads check `token`
+/// before every item and stop as soon as it is cancelled. Items which are being processed
+/// at that moment are finished, so the call returns after at most one item per thread.
+/// If the token is cancelled before all results are received, [`SplitError::Cancelled`]
+/// is returned, and if it is cancelled before the call no thread is spawned. Cancelling
+/// the token after the call has returned has no effect on its results.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::{split_comp_work_cancellable, CancelToken, SplitError};
+///
+/// let token = CancelToken::new();
+/// token.cancel();
+///
+/// let result = split_comp_work_cancellable((1..=34).collect(), |num: i64| num * 2, &token);
+///
+/// assert!(matches!(result, Err(SplitError::Cancelled)));
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_work_cancellable<T, R, F>(vector: Vec<T>, function: F, token: &CancelToken)
+    -> Result<Vec<R>, SplitError>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_cancellable_with_config(vector, function, token, &SplitConfig::default())
+}
+
+// Splitting of cancellable computational work using the configuration
+pub(crate) fn split_comp_work_cancellable_with_config<T, R, F>(vector: Vec<T>, function: F,
+    token: &CancelToken, config: &SplitConfig) -> Result<Vec<R>, SplitError>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    // No threads are spawned for already cancelled work
+    if token.is_cancelled() {
+        return Err(SplitError::Cancelled);
+    }
+    
+    // Only cancellation is reported as an error, other failures are raised
+    split_comp_work_in_threads(vector, function, config, &|_, _| {}, Some(token), None)
+        .map_err(|failure| match failure {
+            WorkFailure::Error(SplitError::Cancelled) => SplitError::Cancelled,
+            failure => failure.raise(),
+        })
+}
+
+// Checking whether the optional token has been cancelled
+pub(crate) fn is_cancelled(token: Option<&CancelToken>) -> bool {
+    token.is_some_and(CancelToken::is_cancelled)
+}
@@ -0,0 +1,110 @@
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+
+use log::trace;
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{result_channel, OutputPair, SplitConfig};
+
+/// Splits computational work on items received from a channel between threads.
+///
+/// Spawns up to [`default_max_threads`](crate::default_max_threads) threads, which pull
+/// items from `receiver`, apply `function` to them and send the results to the returned
+/// receiver. Every item gets its index in order of receiving, and results come together
+/// with these indices in completion order, so the consumer can reorder them if needed.
+/// Threads keep working until all senders of the input channel have been dropped, then
+/// they exit and the output channel closes. Threads also exit if the returned receiver is
+/// dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc;
+///
+/// use splitting_of_work::split_comp_work_channel;
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let results = split_comp_work_channel(receiver, |num: i64| num * 2);
+///
+/// for num in 1..=34 {
+///     sender.send(num).unwrap();
+/// }
+///
+/// drop(sender);
+///
+/// let mut result: Vec<(usize, i64)> = results.iter().collect();
+/// result.sort_by_key(|(index, _)| *index);
+///
+/// assert_eq!(result[0], (0, 2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if no thread can be spawned. If `function` panics, the thread which has called
+/// it exits and its item has no result, other threads keep working.
+pub fn split_comp_work_channel<T, R, F>(receiver: Receiver<T>, function: F) -> Receiver<OutputPair<R>>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_channel_with_config(receiver, function, &SplitConfig::default())
+}
+
+// Splitting of computational work on items received from a channel using the
+// configuration, the maximum number of threads is the number of threads
+pub(crate) fn split_comp_work_channel_with_config<T, R, F>(receiver: Receiver<T>, function: F,
+    config: &SplitConfig) -> Receiver<OutputPair<R>>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    // Input channel shared between threads together with the index of the next item
+    let input = Arc::new(Mutex::new((receiver, 0_usize)));
+    
+    // Function shared between threads
+    let function = Arc::new(function);
+    
+    // Channel for transferring results of computational work
+    let (sender, output) = result_channel(config);
+    
+    // Spawning threads for computational work
+    for i in 0..config.max_threads() {
+        let input_copy = Arc::clone(&input);
+        let function_copy = Arc::clone(&function);
+        let sender_copy = sender.clone();
+        
+        let spawned = config.worker_builder(i).spawn(move || {
+            loop {
+                
+                // Lock is released as soon as the item is received and indexed
+                let received = {
+                    let mut input = input_copy.lock().expect("Input channel is poisoned");
+                    let (receiver, next_index) = &mut *input;
+                    
+                    receiver.recv().map(|item| {
+                        *next_index += 1;
+                        (*next_index - 1, item)
+                    })
+                };
+                
+                let Ok((index, item)) = received else {
+                    break;
+                };
+                
+                if sender_copy.send((index, function_copy(item))).is_err() {
+                    break;
+                }
+            }
+        });
+        
+        match spawned {
+            Ok(_) => {}
+            
+            // Already spawned threads are enough to do the work
+            Err(_) if i > 0 => break,
+            Err(error) => WorkFailure::Error(SplitError::SpawnFailed(error)).raise(),
+        }
+        
+        // Logging debugging information
+        trace!("Thread {} has spawned", i);
+    }
+    
+    output
+}
@@ -0,0 +1,76 @@
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::SplitConfig;
+
+/// Splits computational work on whole chunks between threads.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work), but
+/// `function` is called once per chunk with all items of the chunk, so the per-item call
+/// overhead is avoided and the work can be vectorized. The chunks are the ranges of
+/// [`partition`](crate::partition), and if the work is done in the current thread,
+/// `function` is called once with the whole vector. The results of the chunks are joined
+/// in input order.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_chunked;
+///
+/// let result = split_comp_work_chunked((1..=34).collect(), |chunk: &[i64]| {
+///     chunk.iter().map(|num| num * 2).collect()
+/// });
+///
+/// assert_eq!(result, (1..=34).map(|num| num * 2).collect::<Vec<i64>>());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `function` returns a different number of results than the number of items
+/// in its chunk.
+pub fn split_comp_work_chunked<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, F: Fn(&[T]) -> Vec<R> + Sync {
+    split_comp_work_chunked_with_config(vector, function, &SplitConfig::default())
+}
+
+/// Splits computational work on whole chunks between threads, the same as
+/// [`split_comp_work_chunked`](crate::split_comp_work_chunked).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_map_chunks;
+///
+/// let result = split_comp_map_chunks(vec![1, 2, 3], |chunk: &[i64]| chunk.iter().map(|num| -num).collect());
+///
+/// assert_eq!(result, vec![-1, -2, -3]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `function` returns a different number of results than the number of items
+/// in its chunk.
+pub fn split_comp_map_chunks<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, F: Fn(&[T]) -> Vec<R> + Sync {
+    split_comp_work_chunked(vector, function)
+}
+
+// Splitting of computational work on whole chunks using the configuration
+pub(crate) fn split_comp_work_chunked_with_config<T, R, F>(vector: Vec<T>, function: F, config: &SplitConfig)
+    -> Vec<R>
+    where T: Send, R: Send, F: Fn(&[T]) -> Vec<R> + Sync {
+    let len = vector.len();
+    
+    let chunk_results = run_in_chunks(vector, config, |start, chunk: Vec<T>| {
+        (start, chunk.len(), function(&chunk))
+    });
+    
+    // Lengths are checked in the calling thread, so the panic isn't a panic of a thread
+    let chunk_results: Vec<Vec<R>> = chunk_results.into_iter().map(|(start, chunk_len, chunk_result)| {
+        assert_eq!(chunk_result.len(), chunk_len,
+            "Function returned {} results for the chunk of {} items starting at index {}",
+            chunk_result.len(), chunk_len, start);
+        
+        chunk_result
+    }).collect();
+    
+    concat_in_order(chunk_results, len)
+}
@@ -0,0 +1,87 @@
+use crate::scoped::run_in_chunks;
+use crate::{is_sequential, SplitConfig};
+
+/// Splits computational work between threads and collects the results into any collection.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the results are fed in the
+/// order of the input items into any [`FromIterator`] target, for example a `String`, a
+/// `HashSet` or a `BTreeMap` built from `(K, V)` results. If the vector has fewer items than
+/// [`THRESHOLD`](crate::THRESHOLD), the results are collected directly as they're computed
+/// in the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_collect;
+///
+/// let result: String = split_comp_collect("hello".chars().collect(), |c: char| c.to_ascii_uppercase());
+///
+/// assert_eq!(result, "HELLO");
+/// ```
+pub fn split_comp_collect<T, R, C, F>(vector: Vec<T>, function: F) -> C
+    where T: Send, R: Send, C: FromIterator<R>, F: Fn(T) -> R + Sync {
+    split_comp_collect_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work using the configuration and collecting the results into
+// any collection
+pub(crate) fn split_comp_collect_with_config<T, R, C, F>(vector: Vec<T>, function: F, config: &SplitConfig) -> C
+    where T: Send, R: Send, C: FromIterator<R>, F: Fn(T) -> R + Sync {
+    if is_sequential(vector.len(), config) {
+        return vector.into_iter().map(function).collect();
+    }
+    
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().map(&function).collect::<Vec<R>>()
+    });
+    
+    chunk_results.into_iter().flatten().collect()
+}
+
+/// Splits computational work between threads and appends the results to a vector.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the results are appended in
+/// the order of the input items to `out`, whose items are kept as they are. Capacity for
+/// all results is reserved up front, so results of several batches can be accumulated in
+/// one vector. If the vector has fewer items than [`THRESHOLD`](crate::THRESHOLD), `out` is
+/// extended directly in the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_extend;
+///
+/// let mut out = vec![0];
+///
+/// split_comp_extend(&mut out, vec![1, 2, 3], |num: i64| num * 10);
+/// split_comp_extend(&mut out, vec![4, 5], |num: i64| num * 10);
+///
+/// assert_eq!(out, vec![0, 10, 20, 30, 40, 50]);
+/// ```
+#[doc(alias = "par_extend")]
+pub fn split_comp_extend<T, R, F>(out: &mut Vec<R>, vector: Vec<T>, function: F)
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    split_comp_extend_with_config(out, vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work using the configuration and appending the results to a
+// vector
+pub(crate) fn split_comp_extend_with_config<T, R, F>(out: &mut Vec<R>, vector: Vec<T>, function: F,
+    config: &SplitConfig)
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    out.reserve(vector.len());
+    
+    if is_sequential(vector.len(), config) {
+        out.extend(vector.into_iter().map(function));
+        
+        return;
+    }
+    
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().map(&function).collect::<Vec<R>>()
+    });
+    
+    for chunk_result in chunk_results {
+        out.extend(chunk_result);
+    }
+}
@@ -0,0 +1,638 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::Sum;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::adaptive::split_comp_work_adaptive_with_config;
+use crate::aggregate::{split_comp_count_with_config, split_comp_max_with_config, split_comp_min_with_config,
+    split_comp_sum_with_config};
+use crate::cancel::{split_comp_work_cancellable_with_config, CancelToken};
+use crate::channel::split_comp_work_channel_with_config;
+use crate::chunked::split_comp_work_chunked_with_config;
+use crate::collect::{split_comp_collect_with_config, split_comp_extend_with_config};
+use crate::context::{split_comp_work_mut_with_config, split_comp_work_with_context_with_config,
+    split_comp_work_with_ctx_with_config};
+use crate::dynamic::split_comp_work_dyn_with_config;
+use crate::error::SplitError;
+use crate::fallible::{split_comp_work_catching_with_config, split_comp_work_collect_errors_with_config,
+    try_split_comp_work_with_config, CaughtResult};
+use crate::filter::{split_comp_filter_map_with_config, split_comp_partition_with_config,
+    split_filter_with_config};
+use crate::flat_map::split_comp_flat_map_with_config;
+use crate::for_each::split_comp_for_each_with_config;
+use crate::group::{split_comp_count_by_with_config, split_comp_group_by_with_config};
+use crate::in_place::split_comp_work_in_place_with_config;
+use crate::indexed::{split_comp_work_indexed_with_config, split_comp_work_seeded_with_config};
+use crate::into::{split_comp_work_into_with_config, split_comp_work_reuse_with_config};
+use crate::iter::split_comp_work_iter_with_config;
+use crate::progress::split_comp_work_with_progress_with_config;
+use crate::reduce::{split_comp_fold_with_config, split_reduce_with_config};
+use crate::scoped::{split_comp_work_ref_with_config, split_comp_work_scoped_with_config};
+use crate::search::{split_comp_all_with_config, split_comp_any_with_config,
+    split_comp_find_first_with_config, split_comp_position_with_config, split_comp_rposition_with_config};
+use crate::shared::split_comp_work_shared_with_config;
+use crate::sort::{split_comp_sort_by_key_with_config, split_comp_sort_with_config};
+use crate::stats::{split_comp_work_timed_with_config, split_comp_work_with_stats_with_config, WorkStats};
+use crate::stream::{split_comp_work_stream_with_config, ResultStream};
+use crate::timeout::{split_comp_work_timeout_partial_with_config, split_comp_work_timeout_with_config,
+    PartialResults};
+use crate::unordered::{split_comp_work_unordered_iter_with_config, split_comp_work_unordered_with_config,
+    UnorderedResults};
+use crate::zip::{split_comp_unzip_with_config, split_zip_map_with_config};
+use crate::{default_max_threads, DynFunction, OutputPair, split_comp_work_checked_with_config, split_comp_work_with_config, THRESHOLD};
+
+/// Settings of splitting of computational work.
+///
+/// All tunables are kept here, and new ones are added as builder methods with defaults, so
+/// existing code keeps working. The default configuration uses [`THRESHOLD`] and
+/// [`default_max_threads`], an unbounded channel for results and the default stack size,
+/// so it splits the work the same way as [`split_comp_work`](crate::split_comp_work). Unlike
+/// [`split_comp_work`](crate::split_comp_work), methods of the configuration spawn their own
+/// threads on every call and never use the global pool, whatever
+/// [`init_global_pool`](crate::init_global_pool) has set.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::SplitConfig;
+///
+/// let config = SplitConfig::builder().threshold(16).max_threads(8).build().unwrap();
+///
+/// let result = config.run(0..100, |num: i64| num * 2);
+///
+/// assert_eq!(result, (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitConfig {
+    threshold: usize,
+    max_threads: usize,
+    channel_capacity: Option<usize>,
+    stack_size: Option<usize>,
+    force_sequential: bool,
+}
+
+/// Another name of [`SplitConfig`], so it's built and used the same way.
+pub type WorkConfig = SplitConfig;
+
+impl SplitConfig {
+    
+    /// Creates a builder starting from the default configuration
+    pub fn builder() -> SplitConfigBuilder {
+        SplitConfigBuilder { config: SplitConfig::default() }
+    }
+    
+    /// Minimum length of the vector for which threads are spawned
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+    
+    /// Maximum number of threads
+    pub fn max_threads(&self) -> usize {
+        self.max_threads
+    }
+    
+    /// Maximum number of results waiting in the channel to be received, `None` if the
+    /// channel is unbounded
+    pub fn channel_capacity(&self) -> Option<usize> {
+        self.channel_capacity
+    }
+    
+    /// Stack size of spawned threads in bytes, `None` if the default stack size of the
+    /// standard library is used
+    pub fn stack_size(&self) -> Option<usize> {
+        self.stack_size
+    }
+    
+    /// Whether all work is done in the current thread whatever the length of the vector is
+    pub fn force_sequential(&self) -> bool {
+        self.force_sequential
+    }
+    
+    // Creating the builder of the thread with the given number, threads are named
+    // `split-worker-{i}` so they can be told apart in debuggers and profilers
+    pub(crate) fn worker_builder(&self, i: usize) -> thread::Builder {
+        let builder = thread::Builder::new().name(format!("split-worker-{}", i));
+        
+        match self.stack_size {
+            Some(size) => builder.stack_size(size),
+            None => builder,
+        }
+    }
+    
+    // Copying the configuration with another threshold
+    pub(crate) fn with_threshold(&self, threshold: usize) -> SplitConfig {
+        SplitConfig { threshold, ..self.clone() }
+    }
+    
+    /// Splits computational work between threads using this configuration, like
+    /// [`split_comp_work`](crate::split_comp_work). The threads are spawned for this call
+    /// instead of being taken from the global pool.
+    pub fn run<I, R, F>(&self, input: I, function: F) -> Vec<R>
+        where I: IntoIterator, I::Item: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(I::Item) -> R + Send + Sync {
+        split_comp_work_with_config(input.into_iter().collect(), function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and reports
+    /// failures as errors, like [`split_comp_work_checked`](crate::split_comp_work_checked)
+    pub fn run_checked<T, R, F>(&self, vector: Vec<T>, function: F) -> Result<Vec<R>, SplitError>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_checked_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads choosing the threshold from the measured
+    /// cost of the function, like [`split_comp_work_adaptive`](crate::split_comp_work_adaptive).
+    /// The threshold of this configuration is ignored.
+    pub fn run_adaptive<T, R, F>(&self, vector: Vec<T>, function: F, target: Duration) -> Vec<R>
+        where T: Send, R: Send, F: Fn(T) -> R + Sync {
+        split_comp_work_adaptive_with_config(vector, function, target, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and collects the
+    /// results into any collection, like [`split_comp_collect`](crate::split_comp_collect)
+    pub fn run_collect<T, R, C, F>(&self, vector: Vec<T>, function: F) -> C
+        where T: Send, R: Send, C: FromIterator<R>, F: Fn(T) -> R + Sync {
+        split_comp_collect_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and appends the
+    /// results to a vector, like [`split_comp_extend`](crate::split_comp_extend)
+    pub fn run_extend<T, R, F>(&self, out: &mut Vec<R>, vector: Vec<T>, function: F)
+        where T: Send, R: Send, F: Fn(T) -> R + Sync {
+        split_comp_extend_with_config(out, vector, function, self)
+    }
+    
+    /// Splits computational work with a function chosen at runtime between threads using
+    /// this configuration, like [`split_comp_work_dyn`](crate::split_comp_work_dyn)
+    pub fn run_dyn<T, R>(&self, vector: Vec<T>, function: DynFunction<T, R>) -> Vec<R>
+        where T: 'static + Send, R: 'static + Send {
+        split_comp_work_dyn_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work on shared data between threads using this configuration,
+    /// like [`split_comp_work_shared`](crate::split_comp_work_shared)
+    pub fn run_shared<T, R, F>(&self, data: Arc<[T]>, function: F) -> Vec<R>
+        where T: 'static + Send + Sync, R: 'static + Send,
+        F: 'static + Fn(&T) -> R + Send + Sync {
+        split_comp_work_shared_with_config(data, function, self)
+    }
+    
+    /// Splits computational work on a borrowed slice between scoped threads using this
+    /// configuration, like [`split_comp_work_ref`](crate::split_comp_work_ref)
+    pub fn run_ref<T, R, F>(&self, slice: &[T], function: F) -> Vec<R>
+        where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+        split_comp_work_ref_with_config(slice, function, self)
+    }
+    
+    /// Splits computational work on a borrowed slice between scoped threads writing the
+    /// results into a buffer using this configuration, like
+    /// [`split_comp_work_into`](crate::split_comp_work_into)
+    pub fn run_into<T, R, F>(&self, slice: &[T], function: F, out: &mut [R]) -> Result<(), SplitError>
+        where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+        split_comp_work_into_with_config(slice, function, out, self)
+    }
+    
+    /// Splits computational work on a borrowed slice between scoped threads reusing the
+    /// capacity of a vector for the results using this configuration, like
+    /// [`split_comp_work_reuse`](crate::split_comp_work_reuse)
+    pub fn run_reuse<T, R, F>(&self, slice: &[T], function: F, out: &mut Vec<R>)
+        where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+        split_comp_work_reuse_with_config(slice, function, out, self)
+    }
+    
+    /// Splits computational work between scoped threads using this configuration, like
+    /// [`split_comp_work_scoped`](crate::split_comp_work_scoped)
+    pub fn run_scoped<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(T) -> R + Sync {
+        split_comp_work_scoped_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work on whole chunks between threads using this
+    /// configuration, like [`split_comp_work_chunked`](crate::split_comp_work_chunked)
+    pub fn run_chunked<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(&[T]) -> Vec<R> + Sync {
+        split_comp_work_chunked_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work depending on positions of the items between threads
+    /// using this configuration, like [`split_comp_work_indexed`](crate::split_comp_work_indexed)
+    pub fn run_indexed<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(usize, T) -> R + Sync {
+        split_comp_work_indexed_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work with a seed derived for every item between threads using
+    /// this configuration, like [`split_comp_work_seeded`](crate::split_comp_work_seeded)
+    pub fn run_seeded<T, R, F>(&self, vector: Vec<T>, seed: u64, function: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(u64, T) -> R + Sync {
+        split_comp_work_seeded_with_config(vector, seed, function, self)
+    }
+    
+    /// Splits fallible computational work between threads using this configuration, like
+    /// [`try_split_comp_work`](crate::try_split_comp_work)
+    pub fn try_run<T, R, E, F>(&self, vector: Vec<T>, function: F) -> Result<Vec<R>, E>
+        where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+        try_split_comp_work_with_config(vector, function, self)
+    }
+    
+    /// Splits fallible computational work between threads using this configuration and
+    /// reports all errors, like
+    /// [`split_comp_work_collect_errors`](crate::split_comp_work_collect_errors)
+    pub fn run_collect_errors<T, R, E, F>(&self, vector: Vec<T>, function: F)
+        -> Result<Vec<R>, Vec<(usize, E)>>
+        where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+        split_comp_work_collect_errors_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and catches
+    /// panics of single items, like
+    /// [`split_comp_work_catching`](crate::split_comp_work_catching)
+    pub fn run_catching<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<CaughtResult<R>>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_catching_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work on a mutable slice between scoped threads using this
+    /// configuration, like [`split_comp_work_in_place`](crate::split_comp_work_in_place)
+    pub fn run_in_place<T, F>(&self, data: &mut [T], function: F)
+        where T: Send, F: Fn(&mut T) + Send + Sync {
+        split_comp_work_in_place_with_config(data, function, self)
+    }
+    
+    /// Filters the vector in parallel using this configuration, like
+    /// [`split_filter`](crate::split_filter)
+    pub fn run_filter<T, P>(&self, vector: Vec<T>, predicate: P) -> Vec<T>
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_filter_with_config(vector, predicate, self)
+    }
+    
+    /// Maps and filters the vector in parallel using this configuration, like
+    /// [`split_comp_filter_map`](crate::split_comp_filter_map)
+    pub fn run_filter_map<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: Send, R: Send, F: Fn(T) -> Option<R> + Sync {
+        split_comp_filter_map_with_config(vector, function, self)
+    }
+    
+    /// Groups results of computational work in parallel by keys derived from the items
+    /// using this configuration, like [`split_comp_group_by`](crate::split_comp_group_by)
+    pub fn run_group_by<T, K, R, KF, MF>(&self, vector: Vec<T>, key_fn: KF, map_fn: MF) -> HashMap<K, Vec<R>>
+        where T: Send, K: Eq + Hash + Send, R: Send, KF: Fn(&T) -> K + Sync, MF: Fn(T) -> R + Sync {
+        split_comp_group_by_with_config(vector, key_fn, map_fn, self)
+    }
+    
+    /// Counts items of the vector by keys derived from them in parallel using this
+    /// configuration, like [`split_comp_count_by`](crate::split_comp_count_by)
+    pub fn run_count_by<T, K, KF>(&self, vector: Vec<T>, key_fn: KF) -> HashMap<K, usize>
+        where T: Send, K: Eq + Hash + Send, KF: Fn(&T) -> K + Sync {
+        split_comp_count_by_with_config(vector, key_fn, self)
+    }
+    
+    /// Partitions the vector in parallel using this configuration, like
+    /// [`split_comp_partition`](crate::split_comp_partition)
+    pub fn run_partition<T, P>(&self, vector: Vec<T>, predicate: P) -> (Vec<T>, Vec<T>)
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_partition_with_config(vector, predicate, self)
+    }
+    
+    /// Maps every item of the vector to any number of results in parallel using this
+    /// configuration, like [`split_comp_flat_map`](crate::split_comp_flat_map)
+    pub fn run_flat_map<T, R, I, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: Send, R: Send, I: IntoIterator<Item = R>, F: Fn(T) -> I + Sync {
+        split_comp_flat_map_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads with a context per thread using this
+    /// configuration, like [`split_comp_work_with_ctx`](crate::split_comp_work_with_ctx)
+    pub fn run_with_ctx<T, R, C, M, F>(&self, vector: Vec<T>, make_ctx: M, function: F) -> Vec<R>
+        where T: Send, R: Send, M: Fn() -> C + Sync, F: Fn(&mut C, T) -> R + Sync {
+        split_comp_work_with_ctx_with_config(vector, make_ctx, function, self)
+    }
+    
+    /// Splits computational work between threads with a shared read-only context using
+    /// this configuration, like [`split_comp_work_with_context`](crate::split_comp_work_with_context)
+    pub fn run_with_context<T, R, C, F>(&self, vector: Vec<T>, context: Arc<C>, function: F) -> Vec<R>
+        where T: Send, R: Send, C: Send + Sync, F: Fn(&C, T) -> R + Sync {
+        split_comp_work_with_context_with_config(vector, context, function, self)
+    }
+    
+    /// Splits computational work between threads with a stateful function using this
+    /// configuration, like [`split_comp_work_mut`](crate::split_comp_work_mut)
+    pub fn run_mut<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: Send, R: Send, F: FnMut(T) -> R + Clone + Send + Sync {
+        split_comp_work_mut_with_config(vector, function, self)
+    }
+    
+    /// Maps and reduces the vector in parallel using this configuration, like
+    /// [`split_reduce`](crate::split_reduce)
+    pub fn run_reduce<T, R, M, C>(&self, vector: Vec<T>, map: M, combine: C, identity: R) -> R
+        where T: Send, R: Clone + Send + Sync, M: Fn(T) -> R + Sync, C: Fn(R, R) -> R + Sync {
+        split_reduce_with_config(vector, map, combine, identity, self)
+    }
+    
+    /// Folds the vector in parallel using this configuration, like
+    /// [`split_comp_fold`](crate::split_comp_fold)
+    pub fn run_fold<T, A, F, C>(&self, vector: Vec<T>, identity: A, fold: F, combine: C) -> A
+        where T: Send, A: Clone + Send + Sync, F: Fn(A, T) -> A + Sync, C: Fn(A, A) -> A {
+        split_comp_fold_with_config(vector, identity, fold, combine, self)
+    }
+    
+    /// Sums the vector in parallel using this configuration, like
+    /// [`split_comp_sum`](crate::split_comp_sum)
+    pub fn run_sum<T>(&self, vector: Vec<T>) -> T
+        where T: Sum + Send {
+        split_comp_sum_with_config(vector, self)
+    }
+    
+    /// Finds the minimum of the vector in parallel using this configuration, like
+    /// [`split_comp_min`](crate::split_comp_min)
+    pub fn run_min<T>(&self, vector: Vec<T>) -> Option<T>
+        where T: Ord + Send {
+        split_comp_min_with_config(vector, self)
+    }
+    
+    /// Finds the maximum of the vector in parallel using this configuration, like
+    /// [`split_comp_max`](crate::split_comp_max)
+    pub fn run_max<T>(&self, vector: Vec<T>) -> Option<T>
+        where T: Ord + Send {
+        split_comp_max_with_config(vector, self)
+    }
+    
+    /// Counts items of the vector satisfying the predicate in parallel using this
+    /// configuration, like [`split_comp_count`](crate::split_comp_count)
+    pub fn run_count<T, P>(&self, vector: Vec<T>, predicate: P) -> usize
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_count_with_config(vector, predicate, self)
+    }
+    
+    /// Sorts the vector in parallel using this configuration, like
+    /// [`split_comp_sort`](crate::split_comp_sort)
+    pub fn run_sort<T>(&self, vector: Vec<T>) -> Vec<T>
+        where T: Ord + Send {
+        split_comp_sort_with_config(vector, self)
+    }
+    
+    /// Sorts the vector in parallel by keys derived from the items using this
+    /// configuration, like [`split_comp_sort_by_key`](crate::split_comp_sort_by_key)
+    pub fn run_sort_by_key<T, K, KF>(&self, vector: Vec<T>, key_fn: KF) -> Vec<T>
+        where T: Send, K: Ord, KF: Fn(&T) -> K + Sync {
+        split_comp_sort_by_key_with_config(vector, key_fn, self)
+    }
+    
+    /// Checks in parallel whether any item satisfies the predicate using this
+    /// configuration, like [`split_comp_any`](crate::split_comp_any)
+    pub fn run_any<T, P>(&self, vector: Vec<T>, predicate: P) -> bool
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_any_with_config(vector, predicate, self)
+    }
+    
+    /// Checks in parallel whether all items satisfy the predicate using this
+    /// configuration, like [`split_comp_all`](crate::split_comp_all)
+    pub fn run_all<T, P>(&self, vector: Vec<T>, predicate: P) -> bool
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_all_with_config(vector, predicate, self)
+    }
+    
+    /// Finds in parallel the earliest item satisfying the predicate using this
+    /// configuration, like [`split_comp_find_first`](crate::split_comp_find_first)
+    pub fn run_find_first<T, P>(&self, vector: Vec<T>, predicate: P) -> Option<(usize, T)>
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_find_first_with_config(vector, predicate, self)
+    }
+    
+    /// Finds in parallel the lowest index of an item satisfying the predicate using this
+    /// configuration, like [`split_comp_position`](crate::split_comp_position)
+    pub fn run_position<T, P>(&self, vector: Vec<T>, predicate: P) -> Option<usize>
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_position_with_config(vector, predicate, self)
+    }
+    
+    /// Finds in parallel the highest index of an item satisfying the predicate using this
+    /// configuration, like [`split_comp_rposition`](crate::split_comp_rposition)
+    pub fn run_rposition<T, P>(&self, vector: Vec<T>, predicate: P) -> Option<usize>
+        where T: Send, P: Fn(&T) -> bool + Sync {
+        split_comp_rposition_with_config(vector, predicate, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and reports
+    /// progress, like [`split_comp_work_with_progress`](crate::split_comp_work_with_progress)
+    pub fn run_with_progress<T, R, F, P>(&self, vector: Vec<T>, function: F, progress: P) -> Vec<R>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync, P: Fn(usize, usize) {
+        split_comp_work_with_progress_with_config(vector, function, progress, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and stops it
+    /// when the token is cancelled, like
+    /// [`split_comp_work_cancellable`](crate::split_comp_work_cancellable)
+    pub fn run_cancellable<T, R, F>(&self, vector: Vec<T>, function: F, token: &CancelToken)
+        -> Result<Vec<R>, SplitError>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_cancellable_with_config(vector, function, token, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and reports how
+    /// it has been split, like [`split_comp_work_with_stats`](crate::split_comp_work_with_stats)
+    pub fn run_with_stats<T, R, F>(&self, vector: Vec<T>, function: F) -> (Vec<R>, WorkStats)
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_with_stats_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and reports how
+    /// long every thread has worked, like [`split_comp_work_timed`](crate::split_comp_work_timed)
+    pub fn run_timed<T, R, F>(&self, vector: Vec<T>, function: F) -> (Vec<R>, Vec<Duration>)
+        where T: Send, R: Send, F: Fn(T) -> R + Sync {
+        split_comp_work_timed_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and gives up
+    /// after the timeout, like [`split_comp_work_timeout`](crate::split_comp_work_timeout)
+    pub fn run_timeout<T, R, F>(&self, vector: Vec<T>, function: F, timeout: Duration)
+        -> Result<Vec<R>, SplitError>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_timeout_with_config(vector, function, timeout, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and keeps the
+    /// results received before the deadline, like
+    /// [`split_comp_work_timeout_partial`](crate::split_comp_work_timeout_partial)
+    pub fn run_timeout_partial<T, R, F>(&self, vector: Vec<T>, function: F, timeout: Duration)
+        -> PartialResults<R>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_timeout_partial_with_config(vector, function, timeout, self)
+    }
+    
+    /// Splits computational work on two vectors between threads using this configuration,
+    /// like [`split_zip_map`](crate::split_zip_map)
+    pub fn run_zip_map<A, B, R, F>(&self, a: Vec<A>, b: Vec<B>, function: F) -> Result<Vec<R>, SplitError>
+        where A: Send, B: Send, R: Send, F: Fn(A, B) -> R + Sync {
+        split_zip_map_with_config(a, b, function, self)
+    }
+    
+    /// Splits computational work returning pairs between threads and unzips the results
+    /// using this configuration, like [`split_comp_unzip`](crate::split_comp_unzip)
+    pub fn run_unzip<T, A, B, F>(&self, vector: Vec<T>, function: F) -> (Vec<A>, Vec<B>)
+        where T: Send, A: Send, B: Send, F: Fn(T) -> (A, B) + Sync {
+        split_comp_unzip_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and streams the
+    /// results, like [`split_comp_work_stream`](crate::split_comp_work_stream)
+    pub fn run_stream<T, R, F>(&self, vector: Vec<T>, function: F) -> ResultStream<R>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_stream_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads using this configuration and returns the
+    /// results in completion order, like
+    /// [`split_comp_work_unordered`](crate::split_comp_work_unordered)
+    pub fn run_unordered<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<OutputPair<R>>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_unordered_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work between threads and returns the results lazily in
+    /// completion order using this configuration, like
+    /// [`split_comp_work_unordered_iter`](crate::split_comp_work_unordered_iter)
+    pub fn run_unordered_iter<T, R, F>(&self, vector: Vec<T>, function: F) -> UnorderedResults<R>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_unordered_iter_with_config(vector, function, self)
+    }
+    
+    /// Splits computational work on items of an iterator between threads using this
+    /// configuration, like [`split_comp_work_iter`](crate::split_comp_work_iter)
+    pub fn run_iter<I, R, F>(&self, iter: I, function: F) -> Vec<R>
+        where I: IntoIterator, I::Item: Send, R: Send, F: Fn(I::Item) -> R + Sync {
+        split_comp_work_iter_with_config(iter, function, self)
+    }
+    
+    /// Splits computational work on items received from a channel between threads using
+    /// this configuration, like [`split_comp_work_channel`](crate::split_comp_work_channel)
+    pub fn run_channel<T, R, F>(&self, receiver: Receiver<T>, function: F) -> Receiver<OutputPair<R>>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work_channel_with_config(receiver, function, self)
+    }
+    
+    /// Splits side-effect-only computational work between threads using this
+    /// configuration, like [`split_comp_for_each`](crate::split_comp_for_each)
+    pub fn run_for_each<T, F>(&self, vector: Vec<T>, function: F)
+        where T: Send, F: Fn(T) + Sync {
+        split_comp_for_each_with_config(vector, function, self)
+    }
+}
+
+impl Default for SplitConfig {
+    fn default() -> Self {
+        SplitConfig {
+            threshold: THRESHOLD,
+            max_threads: default_max_threads(),
+            channel_capacity: None,
+            stack_size: None,
+            force_sequential: false,
+        }
+    }
+}
+
+/// Builder of [`SplitConfig`]
+#[derive(Clone, Debug)]
+pub struct SplitConfigBuilder {
+    config: SplitConfig,
+}
+
+impl SplitConfigBuilder {
+    
+    /// Sets minimum length of the vector for which threads are spawned
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.config.threshold = threshold;
+        self
+    }
+    
+    /// Sets maximum number of threads
+    pub fn max_threads(mut self, max_threads: usize) -> Self {
+        self.config.max_threads = max_threads;
+        self
+    }
+    
+    /// Sets maximum number of results waiting in the channel to be received. Threads wait
+    /// while the channel is full, so results which haven't been received yet don't pile up
+    /// in memory. For results sent item by item the capacity counts items, and for results
+    /// sent by whole chunks it counts chunks. A capacity of `0` makes every thread wait
+    /// until its result is received.
+    ///
+    /// The capacity is used by the methods which spawn their own threads and send results
+    /// through a channel, like [`run`](SplitConfig::run), [`run_shared`](SplitConfig::run_shared),
+    /// [`run_channel`](SplitConfig::run_channel), [`run_stream`](SplitConfig::run_stream) and
+    /// [`run_unordered`](SplitConfig::run_unordered). Methods using scoped threads, like
+    /// [`run_ref`](SplitConfig::run_ref), get the results when the threads are joined, so
+    /// they have no channel to bound. [`WorkPool`](crate::WorkPool) and
+    /// [`split_comp_work`](crate::split_comp_work) on the global pool don't take a
+    /// configuration and always use unbounded channels.
+    pub fn channel_capacity(mut self, capacity: usize) -> Self {
+        self.config.channel_capacity = Some(capacity);
+        self
+    }
+    
+    /// Sets stack size of spawned threads in bytes
+    pub fn stack_size(mut self, size: usize) -> Self {
+        self.config.stack_size = Some(size);
+        self
+    }
+    
+    /// Sets whether all work is done in the current thread whatever the length of the
+    /// vector is. It's useful for reproducible tests and for targets without threads.
+    /// [`split_comp_work_channel`](crate::split_comp_work_channel) still needs its threads,
+    /// because it processes items while the caller goes on.
+    pub fn force_sequential(mut self, force_sequential: bool) -> Self {
+        self.config.force_sequential = force_sequential;
+        self
+    }
+    
+    /// Checks the settings and creates the configuration
+    pub fn build(self) -> Result<SplitConfig, ConfigError> {
+        if self.config.threshold == 0 {
+            return Err(ConfigError::ZeroThreshold);
+        }
+        
+        if self.config.max_threads == 0 {
+            return Err(ConfigError::ZeroMaxThreads);
+        }
+        
+        Ok(self.config)
+    }
+}
+
+/// Error of building of [`SplitConfig`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    
+    /// Threshold is zero items
+    ZeroThreshold,
+    
+    /// Maximum number of threads is zero
+    ZeroMaxThreads,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::ZeroThreshold => write!(f, "Threshold must be at least one item"),
+            ConfigError::ZeroMaxThreads => write!(f, "Maximum number of threads must be at least one"),
+        }
+    }
+}
+
+impl Error for ConfigError {}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::{is_sequential, SplitConfig};
+
+/// Splits computational work between threads with a context per thread.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but every thread creates its
+/// own context with `make_ctx` once before processing its chunk, and `function` gets the
+/// context mutably together with every item. Expensive resources like buffers or
+/// connections are created once per thread instead of once per item. If the work is done
+/// in the current thread, `make_ctx` is called once. Every thread owns its context, so
+/// the context doesn't have to be `Send`.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_with_ctx;
+///
+/// let result = split_comp_work_with_ctx(vec!["a", "b"], String::new, |buffer: &mut String, item: &str| {
+///     buffer.clear();
+///     buffer.push_str(item);
+///     buffer.push('!');
+///     buffer.clone()
+/// });
+///
+/// assert_eq!(result, vec!["a!".to_string(), "b!".to_string()]);
+/// ```
+pub fn split_comp_work_with_ctx<T, R, C, M, F>(vector: Vec<T>, make_ctx: M, function: F) -> Vec<R>
+    where T: Send, R: Send, M: Fn() -> C + Sync, F: Fn(&mut C, T) -> R + Sync {
+    split_comp_work_with_ctx_with_config(vector, make_ctx, function, &SplitConfig::default())
+}
+
+/// Splits computational work between threads with a context per thread, the same as
+/// [`split_comp_work_with_ctx`](crate::split_comp_work_with_ctx).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_map_init;
+///
+/// let result = split_comp_map_init(vec![1, 2, 3], || 10, |base: &mut i64, num: i64| *base + num);
+///
+/// assert_eq!(result, vec![11, 12, 13]);
+/// ```
+pub fn split_comp_map_init<T, R, C, M, F>(vector: Vec<T>, make_ctx: M, function: F) -> Vec<R>
+    where T: Send, R: Send, M: Fn() -> C + Sync, F: Fn(&mut C, T) -> R + Sync {
+    split_comp_work_with_ctx(vector, make_ctx, function)
+}
+
+// Splitting of computational work with a context per thread using the configuration
+pub(crate) fn split_comp_work_with_ctx_with_config<T, R, C, M, F>(vector: Vec<T>, make_ctx: M, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, M: Fn() -> C + Sync, F: Fn(&mut C, T) -> R + Sync {
+    let len = vector.len();
+    
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        let mut ctx = make_ctx();
+        
+        chunk.into_iter().map(|item| function(&mut ctx, item)).collect::<Vec<R>>()
+    });
+    
+    concat_in_order(chunk_results, len)
+}
+
+/// Splits computational work between threads with a shared read-only context.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but `function` gets a reference
+/// to `context` together with every item, so big lookup tables can be shared without
+/// globals. The `Arc` is cloned once per thread, not once per item.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+///
+/// use splitting_of_work::split_comp_work_with_context;
+///
+/// let names = Arc::new(HashMap::from([(1, "one"), (2, "two")]));
+///
+/// let result = split_comp_work_with_context(vec![2, 1, 3], names, |names: &HashMap<i64, &str>, num: i64| {
+///     names.get(&num).copied()
+/// });
+///
+/// assert_eq!(result, vec![Some("two"), Some("one"), None]);
+/// ```
+pub fn split_comp_work_with_context<T, R, C, F>(vector: Vec<T>, context: Arc<C>, function: F) -> Vec<R>
+    where T: Send, R: Send, C: Send + Sync, F: Fn(&C, T) -> R + Sync {
+    split_comp_work_with_context_with_config(vector, context, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with a shared read-only context using the configuration
+pub(crate) fn split_comp_work_with_context_with_config<T, R, C, F>(vector: Vec<T>, context: Arc<C>, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, C: Send + Sync, F: Fn(&C, T) -> R + Sync {
+    let len = vector.len();
+    
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        let context = Arc::clone(&context);
+        
+        chunk.into_iter().map(|item| function(&context, item)).collect::<Vec<R>>()
+    });
+    
+    concat_in_order(chunk_results, len)
+}
+
+/// Splits computational work between threads with a stateful function.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but `function` can mutate its
+/// own state, like a reusable buffer. It's cloned once per thread, so the state is per
+/// thread: every clone sees only the items of its chunk, and mutations aren't observed in
+/// other chunks or by the original closure. If the work is done in the current thread,
+/// the original closure is used directly.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_mut;
+///
+/// let mut buffer = String::new();
+///
+/// let result = split_comp_work_mut(vec![1, 2, 3], move |num: i64| {
+///     buffer.clear();
+///     buffer.push_str(&num.to_string());
+///     buffer.len()
+/// });
+///
+/// assert_eq!(result, vec![1, 1, 1]);
+/// ```
+pub fn split_comp_work_mut<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, F: FnMut(T) -> R + Clone + Send + Sync {
+    split_comp_work_mut_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with a stateful function using the configuration
+pub(crate) fn split_comp_work_mut_with_config<T, R, F>(vector: Vec<T>, mut function: F, config: &SplitConfig)
+    -> Vec<R>
+    where T: Send, R: Send, F: FnMut(T) -> R + Clone + Send + Sync {
+    
+    if is_sequential(vector.len(), config) {
+        return vector.into_iter().map(&mut function).collect();
+    }
+    
+    // Every thread gets its own clone of the function as its context
+    split_comp_work_with_ctx_with_config(vector, || function.clone(), |function: &mut F, item: T| function(item),
+        config)
+}
@@ -0,0 +1,36 @@
+use crate::{split_comp_work_with_config, DynFunction, SplitConfig};
+
+/// Splits computational work with a function chosen at runtime between threads.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but takes the function as a
+/// shared trait object, so functions can be built at runtime, stored in collections and
+/// passed around. The `Arc` is shared by the threads instead of copying the function, and
+/// every item is processed through dynamic dispatch.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use splitting_of_work::{split_comp_work_dyn, DynFunction};
+///
+/// let operation = "square";
+///
+/// let function: DynFunction<i64, i64> = match operation {
+///     "square" => Arc::new(|num| num * num),
+///     _ => Arc::new(|num| -num),
+/// };
+///
+/// assert_eq!(split_comp_work_dyn(vec![1, 2, 3], function), vec![1, 4, 9]);
+/// ```
+pub fn split_comp_work_dyn<T, R>(vector: Vec<T>, function: DynFunction<T, R>) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send {
+    split_comp_work_dyn_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with a function chosen at runtime using the configuration
+pub(crate) fn split_comp_work_dyn_with_config<T, R>(vector: Vec<T>, function: DynFunction<T, R>,
+    config: &SplitConfig) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send {
+    split_comp_work_with_config(vector, move |item: T| function(item), config)
+}
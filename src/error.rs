@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::panic;
+
+/// Error of splitting of computational work
+#[derive(Debug)]
+pub enum SplitError {
+    
+    /// Thread doing computational work on the chunk has panicked, so results of its items
+    /// are lost
+    WorkerPanicked {
+        
+        /// Index of the chunk in order of chunks
+        chunk: usize,
+        
+        /// Range of indices of items of the chunk
+        range: Range<usize>,
+    },
+    
+    /// Thread for computational work can't be spawned. Usually the work of such threads
+    /// is done in the current thread instead, this error is returned only where results
+    /// can't be received yet, like with a bounded channel
+    SpawnFailed(io::Error),
+    
+    /// Computational work has been cancelled
+    Cancelled,
+    
+    /// Not all results have been received before the deadline
+    TimedOut {
+        
+        /// Number of items whose results have been received in time
+        completed: usize,
+    },
+    
+    /// Input vectors which have to be processed together have different lengths
+    LengthMismatch {
+        
+        /// Length of the first vector
+        left: usize,
+        
+        /// Length of the second vector
+        right: usize,
+    },
+    
+    /// Results of some items haven't been received
+    ResultsLost {
+        
+        /// Number of items without results
+        missing: usize,
+    },
+    
+    /// Global pool can't be initialized, because it has already been created
+    PoolAlreadyInitialized,
+}
+
+/// Former name of [`SplitError`]. The variants which were planned as `ChannelClosed` and
+/// `ThreadPanicked` are [`SplitError::ResultsLost`] and [`SplitError::WorkerPanicked`]
+#[deprecated(note = "renamed to `SplitError`, match `SplitError::ResultsLost` instead of `ChannelClosed` and \
+    `SplitError::WorkerPanicked` instead of `ThreadPanicked`")]
+pub type WorkError = SplitError;
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitError::WorkerPanicked { chunk, range } => {
+                write!(f, "Thread doing computational work on chunk {} (items {:?}) has panicked", chunk, range)
+            }
+            SplitError::SpawnFailed(error) => write!(f, "Can't spawn thread for computational work: {}", error),
+            SplitError::Cancelled => write!(f, "Computational work has been cancelled"),
+            SplitError::TimedOut { completed } => {
+                write!(f, "Computational work has timed out after {} completed items", completed)
+            }
+            SplitError::LengthMismatch { left, right } => {
+                write!(f, "Input vectors have different lengths: {} and {}", left, right)
+            }
+            SplitError::ResultsLost { missing } => write!(f, "Results of {} items have been lost", missing),
+            SplitError::PoolAlreadyInitialized => write!(f, "Global pool has already been initialized"),
+        }
+    }
+}
+
+impl Error for SplitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SplitError::SpawnFailed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Failure of a thread doing computational work
+pub(crate) enum WorkFailure {
+    
+    // Thread has returned an error
+    Error(SplitError),
+    
+    // Thread doing computational work on the chunk with the range of indices has panicked
+    // with the payload
+    Panic(usize, Range<usize>, Box<dyn Any + Send>),
+}
+
+impl WorkFailure {
+    
+    // Converting the failure into an error, the panic payload is dropped
+    pub(crate) fn into_error(self) -> SplitError {
+        match self {
+            WorkFailure::Error(error) => error,
+            WorkFailure::Panic(chunk, range, _) => SplitError::WorkerPanicked { chunk, range },
+        }
+    }
+    
+    // Raising the failure in the current thread, the panic is resumed with its payload
+    pub(crate) fn raise(self) -> ! {
+        match self {
+            WorkFailure::Error(error) => panic!("{}", error),
+            WorkFailure::Panic(_, _, payload) => panic::resume_unwind(payload),
+        }
+    }
+}
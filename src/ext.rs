@@ -0,0 +1,114 @@
+use crate::filter::split_filter_with_config;
+use crate::for_each::split_comp_for_each_with_config;
+use crate::scoped::split_comp_work_scoped_with_config;
+use crate::{split_comp_work, SplitConfig};
+
+/// Extension trait for splitting of computational work in method-chaining style.
+///
+/// Brought into scope by [`prelude`](crate::prelude).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::prelude::*;
+///
+/// let result = (1..=34).collect::<Vec<i64>>().split_map(|num| num % 2 == 0);
+///
+/// assert_eq!(result[1], true);
+/// ```
+pub trait SplitMapExt<T> {
+    
+    /// Splits computational work between threads like
+    /// [`split_comp_work`](crate::split_comp_work), using the global pool the same way
+    fn split_map<R, F>(self, function: F) -> Vec<R>
+        where R: 'static + Send, F: 'static + Fn(T) -> R + Send + Sync;
+}
+
+impl<T> SplitMapExt<T> for Vec<T>
+    where T: 'static + Send {
+    fn split_map<R, F>(self, function: F) -> Vec<R>
+        where R: 'static + Send, F: 'static + Fn(T) -> R + Send + Sync {
+        split_comp_work(self, function)
+    }
+}
+
+/// Extension trait for splitting of computational work on vectors and slices in
+/// method-chaining style.
+///
+/// It's implemented for `Vec<T>`, whose items are moved to the function, and for `&[T]`,
+/// whose items are borrowed by the function. The work is done in scoped threads, so
+/// neither the items nor the function need to be `'static`. Brought into scope by
+/// [`prelude`](crate::prelude).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::prelude::*;
+///
+/// let numbers = [1, 2, 3, 4];
+///
+/// assert_eq!(vec![1, 2, 3].par_split_map(|num: i64| num * 2), vec![2, 4, 6]);
+/// assert_eq!(numbers.as_slice().par_split_filter(|num| **num % 2 == 0), vec![&2, &4]);
+/// ```
+pub trait ParallelSplit: Sized {
+    
+    /// Type of the items passed to the function
+    type Item;
+    
+    /// Splits computational work between threads like
+    /// [`split_comp_work_scoped`](crate::split_comp_work_scoped)
+    fn par_split_map<R, F>(self, function: F) -> Vec<R>
+        where R: Send, F: Fn(Self::Item) -> R + Sync;
+    
+    /// Splits side-effect-only computational work between threads like
+    /// [`split_comp_for_each`](crate::split_comp_for_each)
+    fn par_split_for_each<F>(self, function: F)
+        where F: Fn(Self::Item) + Sync;
+    
+    /// Keeps the items matching the predicate, checking them in parallel like
+    /// [`split_filter`](crate::split_filter)
+    fn par_split_filter<P>(self, predicate: P) -> Vec<Self::Item>
+        where P: Fn(&Self::Item) -> bool + Sync;
+}
+
+impl<T> ParallelSplit for Vec<T>
+    where T: Send {
+    type Item = T;
+    
+    fn par_split_map<R, F>(self, function: F) -> Vec<R>
+        where R: Send, F: Fn(T) -> R + Sync {
+        split_comp_work_scoped_with_config(self, function, &SplitConfig::default())
+    }
+    
+    fn par_split_for_each<F>(self, function: F)
+        where F: Fn(T) + Sync {
+        split_comp_for_each_with_config(self, function, &SplitConfig::default())
+    }
+    
+    fn par_split_filter<P>(self, predicate: P) -> Vec<T>
+        where P: Fn(&T) -> bool + Sync {
+        split_filter_with_config(self, predicate, &SplitConfig::default())
+    }
+}
+
+// References to the items are split like a vector, so the function can take them with the
+// lifetime of the slice
+impl<'a, T> ParallelSplit for &'a [T]
+    where T: Sync {
+    type Item = &'a T;
+    
+    fn par_split_map<R, F>(self, function: F) -> Vec<R>
+        where R: Send, F: Fn(&'a T) -> R + Sync {
+        self.iter().collect::<Vec<&'a T>>().par_split_map(function)
+    }
+    
+    fn par_split_for_each<F>(self, function: F)
+        where F: Fn(&'a T) + Sync {
+        self.iter().collect::<Vec<&'a T>>().par_split_for_each(function)
+    }
+    
+    fn par_split_filter<P>(self, predicate: P) -> Vec<&'a T>
+        where P: Fn(&&'a T) -> bool + Sync {
+        self.iter().collect::<Vec<&'a T>>().par_split_filter(predicate)
+    }
+}
@@ -0,0 +1,231 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::scoped::run_in_chunks;
+use crate::{split_comp_work_with_config, SplitConfig};
+
+/// Result of the item whose computational work may have panicked, the error holds the
+/// panic payload
+pub type CaughtResult<R> = Result<R, Box<dyn Any + Send>>;
+
+/// Splits fallible computational work between threads.
+///
+/// Applies `function` to every item of `vector` like
+/// [`split_comp_work`](crate::split_comp_work) and returns the results in input order if
+/// all of them are `Ok`. Otherwise the error of the item with the lowest index is
+/// returned, no matter which thread has found its error first. As soon as an error is
+/// found, threads stop processing items with higher indices.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::try_split_comp_work;
+///
+/// let vector: Vec<String> = vec!["1".to_string(), "x".to_string(), "3".to_string()];
+///
+/// let result = try_split_comp_work(vector, |item: String| item.parse::<i64>());
+///
+/// assert!(result.is_err());
+/// ```
+pub fn try_split_comp_work<T, R, E, F>(vector: Vec<T>, function: F) -> Result<Vec<R>, E>
+    where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+    try_split_comp_work_with_config(vector, function, &SplitConfig::default())
+}
+
+/// Splits fallible computational work between threads, the same as
+/// [`try_split_comp_work`](crate::try_split_comp_work).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_try_map;
+///
+/// let result = split_try_map(vec!["1", "2", "3"], |item: &str| item.parse::<i64>());
+///
+/// assert_eq!(result, Ok(vec![1, 2, 3]));
+/// ```
+pub fn split_try_map<T, R, E, F>(vector: Vec<T>, function: F) -> Result<Vec<R>, E>
+    where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+    try_split_comp_work(vector, function)
+}
+
+// Splitting of fallible computational work using the configuration
+pub(crate) fn try_split_comp_work_with_config<T, R, E, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Result<Vec<R>, E>
+    where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+    let len = vector.len();
+    
+    // Lowest index of the item whose error has been found
+    let lowest_error_index = AtomicUsize::new(usize::MAX);
+    
+    let chunk_results = run_in_chunks(vector, config, |start, chunk: Vec<T>| {
+        try_comp_work_on_chunk(start, chunk, &function, &lowest_error_index)
+    });
+    
+    let mut result: Vec<R> = Vec::with_capacity(len);
+    
+    // Chunks are in input order, so the first found error has the lowest index, and
+    // stopped chunks can only follow it
+    for chunk_result in chunk_results {
+        match chunk_result {
+            ChunkOutcome::Done(chunk_result) => result.extend(chunk_result),
+            ChunkOutcome::Failed(error) => return Err(error),
+            ChunkOutcome::Stopped => unreachable!("Chunk has been stopped without a preceding error"),
+        }
+    }
+    
+    Ok(result)
+}
+
+/// Splits fallible computational work between threads and reports all errors.
+///
+/// Applies `function` to every item of `vector` like
+/// [`split_comp_work`](crate::split_comp_work) and returns the results in input order if
+/// all of them are `Ok`. Otherwise all items are still processed, and every error is
+/// returned together with the index of its item, sorted by index.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_collect_errors;
+///
+/// let vector: Vec<&str> = vec!["1", "x", "3", "y"];
+///
+/// let result = split_comp_work_collect_errors(vector, |item: &str| item.parse::<i64>());
+///
+/// let failed_indices: Vec<usize> = result.unwrap_err().into_iter().map(|(index, _)| index).collect();
+///
+/// assert_eq!(failed_indices, vec![1, 3]);
+/// ```
+pub fn split_comp_work_collect_errors<T, R, E, F>(vector: Vec<T>, function: F)
+    -> Result<Vec<R>, Vec<(usize, E)>>
+    where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+    split_comp_work_collect_errors_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of fallible computational work with reporting of all errors using the
+// configuration
+pub(crate) fn split_comp_work_collect_errors_with_config<T, R, E, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Result<Vec<R>, Vec<(usize, E)>>
+    where T: Send, R: Send, E: Send, F: Fn(T) -> Result<R, E> + Sync {
+    let len = vector.len();
+    
+    let chunk_results = run_in_chunks(vector, config, |start, chunk: Vec<T>| {
+        comp_work_on_chunk_collecting_errors(start, chunk, &function)
+    });
+    
+    let mut result: Vec<R> = Vec::with_capacity(len);
+    
+    // Error vector is allocated only if some item has failed
+    let mut errors: Vec<(usize, E)> = Vec::new();
+    
+    // Chunks are in input order, so errors are sorted by index
+    for chunk_result in chunk_results {
+        match chunk_result {
+            Ok(chunk_result) if errors.is_empty() => result.extend(chunk_result),
+            Ok(_) => {}
+            Err(chunk_errors) => errors.extend(chunk_errors),
+        }
+    }
+    
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+// Doing fallible computational work on a chunk starting at the given index, all errors
+// of the chunk are kept
+fn comp_work_on_chunk_collecting_errors<T, R, E, F>(start: usize, chunk: Vec<T>, function: &F)
+    -> Result<Vec<R>, Vec<(usize, E)>> where F: Fn(T) -> Result<R, E> {
+    let mut result: Vec<R> = Vec::with_capacity(chunk.len());
+    let mut errors: Vec<(usize, E)> = Vec::new();
+    
+    for (index, item) in (start..).zip(chunk) {
+        match function(item) {
+            Ok(item_result) if errors.is_empty() => result.push(item_result),
+            Ok(_) => {}
+            Err(error) => errors.push((index, error)),
+        }
+    }
+    
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Splits computational work between threads and catches panics of single items.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but every call of `function`
+/// is wrapped in [`catch_unwind`](std::panic::catch_unwind). If it panics, the panic
+/// payload is returned in the slot of the item, and the rest of the chunk is still
+/// processed. `function` is treated as unwind safe.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_catching;
+///
+/// let result = split_comp_work_catching(vec![1, 0, 2], |num: i64| 10 / num);
+///
+/// assert_eq!(*result[0].as_ref().unwrap(), 10);
+/// assert!(result[1].is_err());
+/// assert_eq!(*result[2].as_ref().unwrap(), 5);
+/// ```
+pub fn split_comp_work_catching<T, R, F>(vector: Vec<T>, function: F) -> Vec<CaughtResult<R>>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_catching_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with catching of panics of single items using the
+// configuration
+pub(crate) fn split_comp_work_catching_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Vec<CaughtResult<R>>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_with_config(vector, move |item: T| {
+        panic::catch_unwind(AssertUnwindSafe(|| function(item)))
+    }, config)
+}
+
+// Outcome of fallible computational work on a chunk
+enum ChunkOutcome<R, E> {
+    
+    // All items of the chunk have been processed
+    Done(Vec<R>),
+    
+    // Processing of the chunk has failed with the error of its first failed item
+    Failed(E),
+    
+    // Processing of the chunk has been stopped because an error with a lower index exists
+    Stopped,
+}
+
+// Doing fallible computational work on a chunk starting at the given index
+fn try_comp_work_on_chunk<T, R, E, F>(start: usize, chunk: Vec<T>, function: &F,
+    lowest_error_index: &AtomicUsize) -> ChunkOutcome<R, E> where F: Fn(T) -> Result<R, E> {
+    let mut result: Vec<R> = Vec::with_capacity(chunk.len());
+    
+    for (index, item) in (start..).zip(chunk) {
+        
+        // Items after an already found error can't change the result
+        if index > lowest_error_index.load(Ordering::Relaxed) {
+            return ChunkOutcome::Stopped;
+        }
+        
+        match function(item) {
+            Ok(item_result) => result.push(item_result),
+            Err(error) => {
+                lowest_error_index.fetch_min(index, Ordering::Relaxed);
+                return ChunkOutcome::Failed(error);
+            }
+        }
+    }
+    
+    ChunkOutcome::Done(result)
+}
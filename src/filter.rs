@@ -0,0 +1,124 @@
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::SplitConfig;
+
+/// Filters the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work) and
+/// returns only the items for which `predicate` returns `true`, in their original relative
+/// order. Only kept items are buffered, so if nothing is kept the output isn't allocated.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_filter;
+///
+/// let result = split_filter((1..=10).collect(), |num: &i64| num % 2 == 0);
+///
+/// assert_eq!(result, vec![2, 4, 6, 8, 10]);
+/// ```
+pub fn split_filter<T, P>(vector: Vec<T>, predicate: P) -> Vec<T>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_filter_with_config(vector, predicate, &SplitConfig::default())
+}
+
+/// Filters the vector in parallel, the same as [`split_filter`](crate::split_filter).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_filter;
+///
+/// let result = split_comp_filter(vec![1, 2, 3, 4], |num: &i64| *num > 2);
+///
+/// assert_eq!(result, vec![3, 4]);
+/// ```
+pub fn split_comp_filter<T, P>(vector: Vec<T>, predicate: P) -> Vec<T>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_filter(vector, predicate)
+}
+
+// Filtering of the vector in parallel using the configuration
+pub(crate) fn split_filter_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig) -> Vec<T>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    
+    // Chunks are filtered locally and joined in their order, so the relative order of
+    // kept items is preserved
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().filter(&predicate).collect::<Vec<T>>()
+    });
+    
+    let len = chunk_results.iter().map(Vec::len).sum();
+    
+    concat_in_order(chunk_results, len)
+}
+
+/// Maps and filters the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work),
+/// applies `function` to every item and returns only the `Some` results, in order of
+/// their input items. Results are compacted in every chunk, so no placeholders are needed.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_filter_map;
+///
+/// let result = split_comp_filter_map(vec!["1", "x", "3"], |item: &str| item.parse::<i64>().ok());
+///
+/// assert_eq!(result, vec![1, 3]);
+/// ```
+pub fn split_comp_filter_map<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, F: Fn(T) -> Option<R> + Sync {
+    split_comp_filter_map_with_config(vector, function, &SplitConfig::default())
+}
+
+// Mapping and filtering of the vector in parallel using the configuration
+pub(crate) fn split_comp_filter_map_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, F: Fn(T) -> Option<R> + Sync {
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().filter_map(&function).collect::<Vec<R>>()
+    });
+    
+    let len = chunk_results.iter().map(Vec::len).sum();
+    
+    concat_in_order(chunk_results, len)
+}
+
+/// Partitions the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work) and
+/// returns the items for which `predicate` returns `true` and the rest of the items, both
+/// in their original relative order, like [`Iterator::partition`]. Every chunk is
+/// classified locally, and the parts of the chunks are joined in chunk order.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_partition;
+///
+/// let (even, odd) = split_comp_partition((1..=10).collect(), |num: &i64| num % 2 == 0);
+///
+/// assert_eq!(even, vec![2, 4, 6, 8, 10]);
+/// assert_eq!(odd, vec![1, 3, 5, 7, 9]);
+/// ```
+pub fn split_comp_partition<T, P>(vector: Vec<T>, predicate: P) -> (Vec<T>, Vec<T>)
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_partition_with_config(vector, predicate, &SplitConfig::default())
+}
+
+// Partitioning of the vector in parallel using the configuration
+pub(crate) fn split_comp_partition_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig)
+    -> (Vec<T>, Vec<T>)
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().partition::<Vec<T>, _>(&predicate)
+    });
+    
+    let (matches, non_matches): (Vec<Vec<T>>, Vec<Vec<T>>) = chunk_results.into_iter().unzip();
+    
+    let matches_len = matches.iter().map(Vec::len).sum();
+    let non_matches_len = non_matches.iter().map(Vec::len).sum();
+    
+    (concat_in_order(matches, matches_len), concat_in_order(non_matches, non_matches_len))
+}
@@ -0,0 +1,54 @@
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::SplitConfig;
+
+/// Maps every item of the vector to any number of results in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work) and
+/// applies `function` to every item. The results of all items are concatenated in input
+/// order, so an item may produce no results at all.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_flat_map;
+///
+/// let result = split_comp_flat_map(vec![1, 2, 3], |num: usize| vec![num; num]);
+///
+/// assert_eq!(result, vec![1, 2, 2, 3, 3, 3]);
+/// ```
+pub fn split_comp_flat_map<T, R, I, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, I: IntoIterator<Item = R>, F: Fn(T) -> I + Sync {
+    split_comp_flat_map_with_config(vector, function, &SplitConfig::default())
+}
+
+/// Maps every item of the vector to any number of results in parallel, the same as
+/// [`split_comp_flat_map`](crate::split_comp_flat_map).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_flat_map;
+///
+/// let result = split_flat_map(vec!["a b", "c"], |line: &str| line.split_whitespace());
+///
+/// assert_eq!(result, vec!["a", "b", "c"]);
+/// ```
+pub fn split_flat_map<T, R, I, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, I: IntoIterator<Item = R>, F: Fn(T) -> I + Sync {
+    split_comp_flat_map(vector, function)
+}
+
+// Mapping of every item to any number of results in parallel using the configuration
+pub(crate) fn split_comp_flat_map_with_config<T, R, I, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, I: IntoIterator<Item = R>, F: Fn(T) -> I + Sync {
+    
+    // Every chunk concatenates results of its items, so empty chunks are just empty vectors
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().flat_map(&function).collect::<Vec<R>>()
+    });
+    
+    let len = chunk_results.iter().map(Vec::len).sum();
+    
+    concat_in_order(chunk_results, len)
+}
@@ -0,0 +1,63 @@
+use crate::scoped::run_in_chunks;
+use crate::SplitConfig;
+
+/// Splits side-effect-only computational work between threads.
+///
+/// Calls `function` on every item of `vector`, splitting the vector into chunks like
+/// [`split_comp_work`](crate::split_comp_work). No results are sent or collected, the
+/// threads are just joined at the end. If the vector has fewer items than
+/// [`THRESHOLD`](crate::THRESHOLD), the work is done in the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicI64, Ordering};
+///
+/// use splitting_of_work::split_comp_for_each;
+///
+/// let sum = AtomicI64::new(0);
+///
+/// split_comp_for_each((1..=34).collect(), |num: i64| {
+///     sum.fetch_add(num, Ordering::Relaxed);
+/// });
+///
+/// assert_eq!(sum.into_inner(), 595);
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_for_each<T, F>(vector: Vec<T>, function: F)
+    where T: Send, F: Fn(T) + Sync {
+    split_comp_for_each_with_config(vector, function, &SplitConfig::default())
+}
+
+/// Splits side-effect-only computational work between threads, the same as
+/// [`split_comp_for_each`](crate::split_comp_for_each).
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use splitting_of_work::split_for_each;
+///
+/// let count = AtomicUsize::new(0);
+///
+/// split_for_each(vec![1, 2, 3], |_: i64| {
+///     count.fetch_add(1, Ordering::Relaxed);
+/// });
+///
+/// assert_eq!(count.into_inner(), 3);
+/// ```
+pub fn split_for_each<T, F>(vector: Vec<T>, function: F)
+    where T: Send, F: Fn(T) + Sync {
+    split_comp_for_each(vector, function)
+}
+
+// Splitting of side-effect-only computational work using the configuration
+pub(crate) fn split_comp_for_each_with_config<T, F>(vector: Vec<T>, function: F, config: &SplitConfig)
+    where T: Send, F: Fn(T) + Sync {
+    run_in_chunks(vector, config, |_, chunk: Vec<T>| chunk.into_iter().for_each(&function));
+}
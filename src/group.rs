@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::scoped::run_in_chunks;
+use crate::SplitConfig;
+
+/// Groups results of computational work in parallel by keys derived from the items.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work). Every
+/// thread groups its chunk into a map of its own, so no locking is needed, and the maps of
+/// the chunks are merged in chunk order. So the results of every key are in the order of
+/// their input items.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_group_by;
+///
+/// let groups = split_comp_group_by((1..=10).collect(), |num: &i64| num % 3, |num: i64| num * 10);
+///
+/// assert_eq!(groups[&1], vec![10, 40, 70, 100]);
+/// assert_eq!(groups[&0], vec![30, 60, 90]);
+/// ```
+pub fn split_comp_group_by<T, K, R, KF, MF>(vector: Vec<T>, key_fn: KF, map_fn: MF) -> HashMap<K, Vec<R>>
+    where T: Send, K: Eq + Hash + Send, R: Send, KF: Fn(&T) -> K + Sync, MF: Fn(T) -> R + Sync {
+    split_comp_group_by_with_config(vector, key_fn, map_fn, &SplitConfig::default())
+}
+
+// Grouping of results of computational work in parallel using the configuration
+pub(crate) fn split_comp_group_by_with_config<T, K, R, KF, MF>(vector: Vec<T>, key_fn: KF, map_fn: MF,
+    config: &SplitConfig) -> HashMap<K, Vec<R>>
+    where T: Send, K: Eq + Hash + Send, R: Send, KF: Fn(&T) -> K + Sync, MF: Fn(T) -> R + Sync {
+    let chunk_groups = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        let mut groups: HashMap<K, Vec<R>> = HashMap::new();
+        
+        for item in chunk {
+            groups.entry(key_fn(&item)).or_default().push(map_fn(item));
+        }
+        
+        groups
+    });
+    
+    // Groups of the first chunk are reused, groups of the next chunks are appended to them
+    let mut chunk_groups = chunk_groups.into_iter();
+    let mut result = chunk_groups.next().unwrap_or_default();
+    
+    for groups in chunk_groups {
+        for (key, results) in groups {
+            result.entry(key).or_default().extend(results);
+        }
+    }
+    
+    result
+}
+
+/// Counts items of the vector by keys derived from them in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work). Every
+/// thread counts the keys of its chunk in a map of its own, and the maps of the chunks are
+/// merged at the end.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_count_by;
+///
+/// let counts = split_comp_count_by((1..=10).collect(), |num: &i64| num % 3);
+///
+/// assert_eq!((counts[&0], counts[&1], counts[&2]), (3, 4, 3));
+/// ```
+pub fn split_comp_count_by<T, K, KF>(vector: Vec<T>, key_fn: KF) -> HashMap<K, usize>
+    where T: Send, K: Eq + Hash + Send, KF: Fn(&T) -> K + Sync {
+    split_comp_count_by_with_config(vector, key_fn, &SplitConfig::default())
+}
+
+// Counting of items by keys in parallel using the configuration
+pub(crate) fn split_comp_count_by_with_config<T, K, KF>(vector: Vec<T>, key_fn: KF, config: &SplitConfig)
+    -> HashMap<K, usize>
+    where T: Send, K: Eq + Hash + Send, KF: Fn(&T) -> K + Sync {
+    let chunk_counts = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        let mut counts: HashMap<K, usize> = HashMap::new();
+        
+        for item in &chunk {
+            *counts.entry(key_fn(item)).or_default() += 1;
+        }
+        
+        counts
+    });
+    
+    let mut chunk_counts = chunk_counts.into_iter();
+    let mut result = chunk_counts.next().unwrap_or_default();
+    
+    for counts in chunk_counts {
+        for (key, count) in counts {
+            *result.entry(key).or_default() += count;
+        }
+    }
+    
+    result
+}
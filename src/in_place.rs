@@ -0,0 +1,92 @@
+use std::panic;
+use std::thread;
+
+use log::debug;
+
+use crate::scoped::spawn_or_run;
+use crate::{chunk_ranges, is_sequential, SplitConfig};
+
+/// Splits computational work on a mutable slice between scoped threads.
+///
+/// Applies `function` to every item of `data` in place. The slice is split into
+/// non-overlapping mutable chunks like in [`split_comp_work`](crate::split_comp_work), so
+/// no second vector is allocated. If the slice has fewer items than
+/// [`THRESHOLD`](crate::THRESHOLD), the work is done in the current thread. A vector owned
+/// by the caller is passed as `&mut vector` and stays usable after the call.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_in_place;
+///
+/// let mut data: Vec<i64> = (0..100).collect();
+///
+/// split_comp_work_in_place(&mut data, |num: &mut i64| *num *= *num);
+///
+/// assert_eq!(data, (0..100).map(|num| num * num).collect::<Vec<i64>>());
+/// ```
+pub fn split_comp_work_in_place<T, F>(data: &mut [T], function: F)
+    where T: Send, F: Fn(&mut T) + Send + Sync {
+    split_comp_work_in_place_with_config(data, function, &SplitConfig::default())
+}
+
+/// Splits computational work on a mutable slice between scoped threads, the same as
+/// [`split_comp_work_in_place`](crate::split_comp_work_in_place).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_map_in_place;
+///
+/// let mut data = vec![1, 2, 3];
+///
+/// split_comp_map_in_place(&mut data, |num: &mut i64| *num += 1);
+///
+/// assert_eq!(data, vec![2, 3, 4]);
+/// ```
+pub fn split_comp_map_in_place<T, F>(data: &mut [T], function: F)
+    where T: Send, F: Fn(&mut T) + Send + Sync {
+    split_comp_work_in_place(data, function)
+}
+
+// Splitting of computational work on a mutable slice using the configuration
+pub(crate) fn split_comp_work_in_place_with_config<T, F>(data: &mut [T], function: F,
+    config: &SplitConfig) where T: Send, F: Fn(&mut T) + Send + Sync {
+    
+    if is_sequential(data.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        data.iter_mut().for_each(function);
+        
+        return;
+    }
+    
+    let function = &function;
+    let ranges = chunk_ranges(data.len(), config);
+    
+    thread::scope(|scope| {
+        let mut rest = data;
+        let mut handles = Vec::with_capacity(ranges.len());
+        
+        // Spawning threads for computational work
+        for (i, range) in ranges.into_iter().enumerate() {
+            
+            // Splitting off the chunk of the thread
+            let (chunk, tail) = rest.split_at_mut(range.len());
+            rest = tail;
+            
+            let worker = move |chunk: &mut [T]| chunk.iter_mut().for_each(function);
+            
+            handles.push(spawn_or_run(scope, config, i, chunk, worker));
+        }
+        
+        // Checking that all threads have done their work
+        for handle in handles {
+            if let Err(payload) = handle.join() {
+                panic::resume_unwind(payload);
+            }
+        }
+    });
+}
@@ -0,0 +1,79 @@
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::SplitConfig;
+
+/// Splits computational work depending on positions of the items between threads.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but `function` gets the index
+/// of every item in the input vector together with the item. The indices are global, not
+/// offsets in the chunks, and they are the same whether the work is done in threads or in
+/// the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_indexed;
+///
+/// let result = split_comp_work_indexed(vec![10, 20, 30], |index: usize, num: i64| index as i64 + num);
+///
+/// assert_eq!(result, vec![10, 21, 32]);
+/// ```
+pub fn split_comp_work_indexed<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, F: Fn(usize, T) -> R + Sync {
+    split_comp_work_indexed_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work depending on positions of the items using the configuration
+pub(crate) fn split_comp_work_indexed_with_config<T, R, F>(vector: Vec<T>, function: F, config: &SplitConfig)
+    -> Vec<R>
+    where T: Send, R: Send, F: Fn(usize, T) -> R + Sync {
+    let len = vector.len();
+    
+    // Indices of the chunk start at the index of its first item in the vector
+    let chunk_results = run_in_chunks(vector, config, |start, chunk: Vec<T>| {
+        (start..).zip(chunk).map(|(index, item)| function(index, item)).collect::<Vec<R>>()
+    });
+    
+    concat_in_order(chunk_results, len)
+}
+
+/// Splits computational work with a seed derived for every item between threads.
+///
+/// Works like [`split_comp_work_indexed`](crate::split_comp_work_indexed), but `function`
+/// gets a sub-seed of every item instead of its index, for example to seed a random number
+/// generator of the item. The sub-seed depends only on `seed` and the index of the item, so
+/// the results are the same in every run whatever the number of threads is. Sub-seeds of
+/// neighbouring items are mixed so they don't share bits.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_seeded;
+///
+/// let first = split_comp_work_seeded(vec![1, 2, 3], 42, |sub_seed: u64, num: u64| sub_seed % 100 + num);
+/// let second = split_comp_work_seeded(vec![1, 2, 3], 42, |sub_seed: u64, num: u64| sub_seed % 100 + num);
+///
+/// assert_eq!(first, second);
+/// ```
+pub fn split_comp_work_seeded<T, R, F>(vector: Vec<T>, seed: u64, function: F) -> Vec<R>
+    where T: Send, R: Send, F: Fn(u64, T) -> R + Sync {
+    split_comp_work_seeded_with_config(vector, seed, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with a seed derived for every item using the
+// configuration
+pub(crate) fn split_comp_work_seeded_with_config<T, R, F>(vector: Vec<T>, seed: u64, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, F: Fn(u64, T) -> R + Sync {
+    split_comp_work_indexed_with_config(vector, |index, item| function(item_seed(seed, index), item), config)
+}
+
+// Deriving the sub-seed of the item with the given index, it's the SplitMix64 output for
+// the index-th step from the seed
+fn item_seed(seed: u64, index: usize) -> u64 {
+    let mut z = seed.wrapping_add((index as u64).wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    
+    z ^ (z >> 31)
+}
@@ -0,0 +1,180 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+use log::debug;
+
+use crate::error::SplitError;
+use crate::scoped::spawn_or_run;
+use crate::{chunk_ranges, is_sequential, SplitConfig};
+
+/// Splits computational work between scoped threads writing the results into a buffer.
+///
+/// Works like [`split_comp_work_ref`](crate::split_comp_work_ref), but the result of every
+/// item is written to the same index of `out`, so no vector of results is allocated and
+/// one buffer can be reused in a loop. Every thread gets the matching chunk of `out`, so
+/// no channel is used. If the slice has fewer items than [`THRESHOLD`](crate::THRESHOLD),
+/// the work is done in the current thread without any allocation.
+///
+/// # Errors
+///
+/// Returns [`SplitError::LengthMismatch`] if `out` isn't as long as `slice`. Nothing is
+/// written to `out` then.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_into;
+///
+/// let mut out = vec![0; 4];
+///
+/// split_comp_work_into(&[1, 2, 3, 4], |num: &i64| num * 10, &mut out).unwrap();
+///
+/// assert_eq!(out, vec![10, 20, 30, 40]);
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_work_into<T, R, F>(slice: &[T], function: F, out: &mut [R]) -> Result<(), SplitError>
+    where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    split_comp_work_into_with_config(slice, function, out, &SplitConfig::default())
+}
+
+// Splitting of computational work writing the results into a buffer using the configuration
+pub(crate) fn split_comp_work_into_with_config<T, R, F>(slice: &[T], function: F, out: &mut [R],
+    config: &SplitConfig) -> Result<(), SplitError>
+    where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    
+    if slice.len() != out.len() {
+        return Err(SplitError::LengthMismatch { left: slice.len(), right: out.len() });
+    }
+    
+    if is_sequential(slice.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        write_results(slice, &function, out);
+        
+        return Ok(());
+    }
+    
+    write_in_threads(slice, out, config, |items, out| write_results(items, &function, out));
+    
+    Ok(())
+}
+
+/// Splits computational work between scoped threads reusing the capacity of a vector for
+/// the results.
+///
+/// Works like [`split_comp_work_ref`](crate::split_comp_work_ref), but `out` is cleared and
+/// filled with the results, so one vector can be reused in a loop whatever the lengths of
+/// the slices are. Unlike [`split_comp_work_into`](crate::split_comp_work_into) the lengths
+/// don't have to match. If the slice has fewer items than [`THRESHOLD`](crate::THRESHOLD),
+/// the results are pushed to `out` directly in the current thread, otherwise every thread
+/// writes the results of its chunk directly into the reserved capacity of `out`, so no
+/// other buffer for results is allocated.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_reuse;
+///
+/// let mut out = Vec::new();
+///
+/// split_comp_work_reuse(&[1, 2, 3], |num: &i64| num * 10, &mut out);
+/// assert_eq!(out, vec![10, 20, 30]);
+///
+/// split_comp_work_reuse(&[4, 5], |num: &i64| num * 10, &mut out);
+/// assert_eq!(out, vec![40, 50]);
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished. `out` is left empty then, so results of a previous call
+/// can't be taken for new ones.
+pub fn split_comp_work_reuse<T, R, F>(slice: &[T], function: F, out: &mut Vec<R>)
+    where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    split_comp_work_reuse_with_config(slice, function, out, &SplitConfig::default())
+}
+
+// Splitting of computational work reusing the capacity of a vector for the results using
+// the configuration
+pub(crate) fn split_comp_work_reuse_with_config<T, R, F>(slice: &[T], function: F, out: &mut Vec<R>,
+    config: &SplitConfig)
+    where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    out.clear();
+    out.reserve(slice.len());
+    
+    if is_sequential(slice.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        // Results pushed before the panic are removed, so the vector is left empty
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| out.extend(slice.iter().map(&function))));
+        
+        if let Err(payload) = outcome {
+            out.clear();
+            panic::resume_unwind(payload);
+        }
+        
+        return;
+    }
+    
+    let len = slice.len();
+    
+    // Every thread writes the results of its chunk into its part of the reserved capacity,
+    // values written before a panic are leaked rather than dropped
+    write_in_threads(slice, &mut out.spare_capacity_mut()[..len], config, |items, slots| {
+        for (slot, item) in slots.iter_mut().zip(items) {
+            slot.write(function(item));
+        }
+    });
+    
+    // SAFETY: the capacity has been reserved above and all `len` slots have been
+    // initialized, because a panic of any thread is resumed before this point
+    unsafe { out.set_len(len) };
+}
+
+// Splitting the slice and the buffer of the same length into matching chunks, and writing
+// every chunk of the buffer in its own scoped thread
+fn write_in_threads<T, O, W>(slice: &[T], out: &mut [O], config: &SplitConfig, write: W)
+    where T: Sync, O: Send, W: Fn(&[T], &mut [O]) + Sync {
+    let write = &write;
+    let ranges = chunk_ranges(slice.len(), config);
+    
+    thread::scope(|scope| {
+        let mut rest = out;
+        let mut handles = Vec::with_capacity(ranges.len());
+        
+        // Spawning threads for computational work
+        for (i, range) in ranges.into_iter().enumerate() {
+            
+            // Splitting off the part of the buffer for the chunk of the thread
+            let (chunk_out, tail) = rest.split_at_mut(range.len());
+            rest = tail;
+            
+            let worker = move |(items, out): (&[T], &mut [O])| write(items, out);
+            
+            handles.push(spawn_or_run(scope, config, i, (&slice[range], chunk_out), worker));
+        }
+        
+        // Checking that all threads have done their work
+        for handle in handles {
+            if let Err(payload) = handle.join() {
+                panic::resume_unwind(payload);
+            }
+        }
+    });
+}
+
+// Writing results of the items to the same indices of the buffer
+fn write_results<T, R, F>(items: &[T], function: &F, out: &mut [R])
+    where F: Fn(&T) -> R {
+    for (result, item) in out.iter_mut().zip(items) {
+        *result = function(item);
+    }
+}
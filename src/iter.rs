@@ -0,0 +1,134 @@
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use log::{debug, trace};
+
+use crate::scoped::{join_in_order, spawn_scoped_worker, ScopedWorker};
+use crate::SplitConfig;
+
+/// Splits computational work on items of an iterator between threads.
+///
+/// Applies `function` to every item of `iter` and returns the results in input order.
+/// Unlike [`split_comp_work`](crate::split_comp_work), the items aren't collected into a
+/// vector first: they are pulled from the iterator in batches of [`THRESHOLD`](crate::THRESHOLD)
+/// items, and the batches are dispatched to threads while the iterator is still being
+/// consumed. Only a few batches wait for free threads at any moment, so the peak memory
+/// doesn't depend on the number of items. If the iterator ends within the first batch,
+/// the work is done in the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_iter;
+///
+/// let result = split_comp_work_iter((1..=34).map(|num| num * 10), |num: i64| num + 1);
+///
+/// assert_eq!(result, (1..=34).map(|num| num * 10 + 1).collect::<Vec<i64>>());
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_work_iter<I, R, F>(iter: I, function: F) -> Vec<R>
+    where I: IntoIterator, I::Item: Send, R: Send, F: Fn(I::Item) -> R + Sync {
+    split_comp_work_iter_with_config(iter, function, &SplitConfig::default())
+}
+
+// Splitting of computational work on items of an iterator using the configuration
+pub(crate) fn split_comp_work_iter_with_config<I, R, F>(iter: I, function: F, config: &SplitConfig) -> Vec<R>
+    where I: IntoIterator, I::Item: Send, R: Send, F: Fn(I::Item) -> R + Sync {
+    let mut items = iter.into_iter();
+    let batch_size = config.threshold();
+    
+    // Length of the input is unknown, so the first batch decides whether threads are needed
+    let first_batch: Vec<I::Item> = (&mut items).take(batch_size).collect();
+    
+    if first_batch.len() < batch_size || config.max_threads() == 1 || config.force_sequential() {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        return first_batch.into_iter().chain(items).map(function).collect();
+    }
+    
+    let num_of_threads = config.max_threads();
+    let function = &function;
+    
+    // Channel for transferring batches to threads, it's bounded so that the iterator isn't
+    // consumed much faster than batches are processed
+    let (batch_sender, batch_receiver) = mpsc::sync_channel::<(usize, Vec<I::Item>)>(num_of_threads);
+    
+    // Every thread owns a copy of the receiver, so once all threads have exited the receiver
+    // is dropped and sending of batches fails instead of blocking
+    let batch_receiver = Arc::new(Mutex::new(batch_receiver));
+    
+    thread::scope(|scope| {
+        
+        // Channel for transferring results of batches together with numbers of batches
+        let (result_sender, result_receiver) = mpsc::channel::<(usize, Vec<R>)>();
+        
+        // Spawning threads for computational work
+        let handles: Vec<_> = (0..num_of_threads).map(|i| {
+            let result_sender = result_sender.clone();
+            let batch_receiver = Arc::clone(&batch_receiver);
+            
+            let handle = spawn_scoped_worker(scope, config, i, move || {
+                loop {
+                    
+                    // Lock is released as soon as the batch is received
+                    let received = batch_receiver.lock().expect("Receiver of batches is poisoned").recv();
+                    
+                    let Ok((num_of_batch, batch)) = received else {
+                        break;
+                    };
+                    
+                    let batch_result: Vec<R> = batch.into_iter().map(function).collect();
+                    
+                    if result_sender.send((num_of_batch, batch_result)).is_err() {
+                        break;
+                    }
+                }
+            });
+            
+            // Logging debugging information
+            trace!("Thread {} has spawned", i);
+            
+            ScopedWorker::Thread(handle)
+        }).collect();
+        
+        // Releasing the first non-used sender and receiver
+        drop(result_sender);
+        drop(batch_receiver);
+        
+        // Dispatching batches while the iterator isn't exhausted, dispatching stops early if
+        // all threads have panicked
+        let mut batch = first_batch;
+        let mut num_of_batches: usize = 0;
+        
+        while !batch.is_empty() {
+            if batch_sender.send((num_of_batches, batch)).is_err() {
+                break;
+            }
+            
+            num_of_batches += 1;
+            batch = (&mut items).take(batch_size).collect();
+        }
+        
+        // Threads finish after the last batch
+        drop(batch_sender);
+        
+        // Receiving results of batches
+        let mut batch_results: Vec<Option<Vec<R>>> = Vec::new();
+        batch_results.resize_with(num_of_batches, || None);
+        
+        for (num_of_batch, batch_result) in result_receiver {
+            batch_results[num_of_batch] = Some(batch_result);
+        }
+        
+        // Resuming the panic of any thread, so no batch can be missing afterwards
+        join_in_order(handles);
+        
+        batch_results.into_iter().flatten().flatten().collect()
+    })
+}
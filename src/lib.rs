@@ -0,0 +1,903 @@
+//! Splitting of computational work between threads
+//!
+//! The input vector is split into chunks and every chunk is processed by its own thread.
+//! Vectors shorter than [`THRESHOLD`] are processed in the current thread.
+//!
+//! Diagnostic messages are emitted through the [`log`](https://docs.rs/log) crate at `debug`
+//! and `trace` levels, so they are shown only if the application installs a logger.
+//!
+//! # Features
+//!
+//! - `std` (enabled by default) provides everything that uses threads: [`split_comp_work`]
+//!   and all the other functions, [`SplitConfig`], [`WorkPool`] and the error types.
+//! - Without `std` the crate is `no_std` and needs only `alloc`. It provides [`partition`],
+//!   [`execute_with`] for running the chunks on a custom executor, the [`Job`], [`Function`],
+//!   [`InputPair`] and [`OutputPair`] aliases, and the [`THRESHOLD`] and
+//!   [`MAX_NUM_OF_THREADS`] constants.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Marking every item of the block as requiring the `std` feature
+macro_rules! cfg_std {
+    ($($item:item)*) => {
+        $(
+            #[cfg(feature = "std")]
+            $item
+        )*
+    };
+}
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+cfg_std! {
+    use std::collections::BTreeMap;
+    use std::iter::Zip;
+    use std::marker::Send;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::thread::{self, JoinHandle};
+    use std::sync::{Arc, OnceLock, mpsc, mpsc::RecvTimeoutError, mpsc::SendError, mpsc::Sender, mpsc::SyncSender,
+        mpsc::Receiver};
+    use std::time::Instant;
+    use std::vec;
+    
+    use log::{debug, trace};
+    
+    mod adaptive;
+    mod aggregate;
+    mod cancel;
+    mod channel;
+    mod chunked;
+    mod collect;
+    mod config;
+    mod context;
+    mod dynamic;
+    mod error;
+    mod ext;
+    mod fallible;
+    mod filter;
+    mod flat_map;
+    mod for_each;
+    mod group;
+    mod in_place;
+    mod indexed;
+    mod into;
+    mod iter;
+    mod pool;
+    pub mod prelude;
+    mod progress;
+    mod reduce;
+    mod scoped;
+    mod search;
+    mod shared;
+    mod sort;
+    mod stats;
+    mod stream;
+    mod timeout;
+    mod unordered;
+    mod zip;
+    
+    pub use adaptive::split_comp_work_adaptive;
+    pub use aggregate::{split_comp_count, split_comp_max, split_comp_min, split_comp_sum};
+    pub use cancel::{split_comp_work_cancellable, CancelToken};
+    pub use channel::split_comp_work_channel;
+    pub use chunked::{split_comp_map_chunks, split_comp_work_chunked};
+    pub use collect::{split_comp_collect, split_comp_extend};
+    pub use config::{ConfigError, SplitConfig, SplitConfigBuilder, WorkConfig};
+    pub use context::{split_comp_map_init, split_comp_work_mut, split_comp_work_with_context, split_comp_work_with_ctx};
+    pub use dynamic::split_comp_work_dyn;
+    #[allow(deprecated)]
+    pub use error::{SplitError, WorkError};
+    pub use ext::{ParallelSplit, SplitMapExt};
+    pub use fallible::{split_comp_work_catching, split_comp_work_collect_errors, split_try_map, try_split_comp_work,
+        CaughtResult};
+    pub use filter::{split_comp_filter, split_comp_filter_map, split_comp_partition, split_filter};
+    pub use flat_map::{split_comp_flat_map, split_flat_map};
+    pub use for_each::{split_comp_for_each, split_for_each};
+    pub use group::{split_comp_count_by, split_comp_group_by};
+    pub use in_place::{split_comp_map_in_place, split_comp_work_in_place};
+    pub use indexed::{split_comp_work_indexed, split_comp_work_seeded};
+    pub use into::{split_comp_work_into, split_comp_work_reuse};
+    pub use iter::split_comp_work_iter;
+    pub use pool::{init_global_pool, WorkPool, WorkerPool};
+    pub use progress::split_comp_work_with_progress;
+    pub use reduce::{split_comp_fold, split_reduce};
+    pub use scoped::{split_comp_work_ref, split_comp_work_scoped};
+    pub use search::{split_comp_all, split_comp_any, split_comp_find_first, split_comp_position,
+        split_comp_rposition, split_find};
+    pub use shared::split_comp_work_shared;
+    pub use sort::{split_comp_sort, split_comp_sort_by_key};
+    pub use stats::{split_comp_work_timed, split_comp_work_with_stats, WorkStats};
+    pub use stream::{split_comp_work_ordered_stream, split_comp_work_stream, ResultStream};
+    pub use timeout::{split_comp_work_timeout, split_comp_work_timeout_partial, PartialResults};
+    pub use unordered::{split_comp_work_unordered, split_comp_work_unordered_iter, UnorderedResults};
+    pub use zip::{split_comp_unzip, split_comp_zip_map, split_zip_map};
+    
+    use cancel::is_cancelled;
+    use error::WorkFailure;
+    use pool::{global_pool, is_pool_thread};
+}
+
+/// Plain function pointer, which coerces to `Fn` and can be passed as is
+pub type Function<T, R> = fn(t: T) -> R;
+
+/// Shared trait object of a function, which can be chosen at runtime
+#[cfg(feature = "std")]
+pub type DynFunction<T, R> = Arc<dyn Fn(T) -> R + Send + Sync>;
+
+/// Input item together with its index in the input vector
+pub type InputPair<T> = (usize, T);
+
+/// Result together with the index of the input item it was computed from
+pub type OutputPair<R> = (usize, R);
+
+// Handle of a thread together with index of its chunk and the range of indices it processes
+#[cfg(feature = "std")]
+pub(crate) type WorkerHandle = (usize, Range<usize>, WorkerJoin);
+
+// Way to get the outcome of the work on a chunk
+#[cfg(feature = "std")]
+pub(crate) enum WorkerJoin {
+    
+    // The chunk is processed by a spawned thread
+    Thread(JoinHandle<Result<(), SplitError>>),
+    
+    // The thread couldn't be spawned, so the chunk has been processed in the current thread
+    Inline(thread::Result<Result<(), SplitError>>),
+}
+
+#[cfg(feature = "std")]
+impl WorkerJoin {
+    
+    // Waiting for the outcome of the work
+    pub(crate) fn join(self) -> thread::Result<Result<(), SplitError>> {
+        match self {
+            WorkerJoin::Thread(handle) => handle.join(),
+            WorkerJoin::Inline(outcome) => outcome,
+        }
+    }
+    
+    // Checking whether the work is done, so joining doesn't block
+    pub(crate) fn is_finished(&self) -> bool {
+        match self {
+            WorkerJoin::Thread(handle) => handle.is_finished(),
+            WorkerJoin::Inline(_) => true,
+        }
+    }
+}
+
+// Sending side of a channel for results, the bounded channel blocks senders while it's full
+#[cfg(feature = "std")]
+pub(crate) enum ResultSender<M> {
+    Unbounded(Sender<M>),
+    Bounded(SyncSender<M>),
+}
+
+#[cfg(feature = "std")]
+impl<M> ResultSender<M> {
+    
+    // Sending the message, waiting for free space in the bounded channel
+    pub(crate) fn send(&self, message: M) -> Result<(), SendError<M>> {
+        match self {
+            ResultSender::Unbounded(sender) => sender.send(message),
+            ResultSender::Bounded(sender) => sender.send(message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> Clone for ResultSender<M> {
+    fn clone(&self) -> Self {
+        match self {
+            ResultSender::Unbounded(sender) => ResultSender::Unbounded(sender.clone()),
+            ResultSender::Bounded(sender) => ResultSender::Bounded(sender.clone()),
+        }
+    }
+}
+
+// Creating a channel for results, it's bounded if the configuration sets its capacity
+#[cfg(feature = "std")]
+pub(crate) fn result_channel<M>(config: &SplitConfig) -> (ResultSender<M>, Receiver<M>) {
+    match config.channel_capacity() {
+        None => {
+            let (sender, receiver) = mpsc::channel();
+            (ResultSender::Unbounded(sender), receiver)
+        }
+        Some(capacity) => {
+            let (sender, receiver) = mpsc::sync_channel(capacity);
+            (ResultSender::Bounded(sender), receiver)
+        }
+    }
+}
+
+// Items of a chunk together with their indices
+#[cfg(feature = "std")]
+pub(crate) type ChunkItems<T> = Zip<Range<usize>, vec::IntoIter<T>>;
+
+// Results of a chunk together with the index of its first item
+#[cfg(feature = "std")]
+type ChunkPair<R> = (usize, Vec<R>);
+
+// Outcome of a thread together with index of its chunk and the range of indices it processes
+#[cfg(feature = "std")]
+pub(crate) type WorkerOutcome = (usize, Range<usize>, thread::Result<Result<(), SplitError>>);
+
+/// Minimum length of the vector for which threads are spawned
+pub const THRESHOLD: usize = 8;
+
+/// Maximum number of threads
+pub const MAX_NUM_OF_THREADS: usize = 64;
+
+/// Returns default maximum number of threads.
+///
+/// It's the number of threads the hardware can run in parallel, but not more than
+/// [`MAX_NUM_OF_THREADS`]. If the number can't be detected, `1` is returned. The number
+/// is detected once, on the first call.
+#[cfg(feature = "std")]
+pub fn default_max_threads() -> usize {
+    
+    // Detection reads system files on some platforms, so it's too slow to repeat on
+    // every call
+    static DEFAULT_MAX_THREADS: OnceLock<usize> = OnceLock::new();
+    
+    *DEFAULT_MAX_THREADS.get_or_init(|| {
+        let available = thread::available_parallelism().map(|num| num.get()).unwrap_or(1);
+        
+        available.min(MAX_NUM_OF_THREADS)
+    })
+}
+
+/// Splits computational work between threads.
+///
+/// Applies `function` to every item of `input` and returns the results in input order.
+/// The input can be a vector or anything else which can be iterated over, like an array or
+/// a `VecDeque`; it is collected into a vector once, preallocated by the length of the
+/// iterator if it is known. If the input has fewer items than [`THRESHOLD`], the work is
+/// done in the current thread and no threads are spawned, so empty and single-element
+/// vectors never spawn threads. Otherwise the vector is split into chunks of about
+/// [`THRESHOLD`] items each, and every chunk is processed by a thread of the global pool,
+/// with at most one chunk per thread of the pool.
+///
+/// The global pool is created on the first call which needs threads, with
+/// [`default_max_threads`] threads, unless [`init_global_pool`] has created it before. Its
+/// threads are reused by all later calls, so no threads are spawned per call. Calls made
+/// by `function` itself from a thread of a pool spawn their own threads instead, so they
+/// never wait for the threads which wait for them.
+///
+/// Chunks are contiguous ranges of indices whose sizes differ by at most one item, and the
+/// results are put back into input order whatever order the threads finish in. So for a
+/// pure `function` the result is always the same as of mapping the input in the current
+/// thread.
+///
+/// `function` can be a plain function or a closure capturing its environment. It is
+/// shared between the threads, so it doesn't have to implement `Clone`.
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work;
+///
+/// let factor: i64 = 3;
+/// let vector: Vec<i64> = (1..=34).collect();
+///
+/// let result = split_comp_work(vector, move |num: i64| num * factor);
+///
+/// assert_eq!(result, (1..=34).map(|num| num * 3).collect::<Vec<i64>>());
+/// ```
+///
+/// Arrays and other collections are accepted as is:
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use splitting_of_work::split_comp_work;
+///
+/// assert_eq!(split_comp_work([1, 2, 3, 4], |num: i64| num + 1), vec![2, 3, 4, 5]);
+///
+/// let deque: VecDeque<i64> = (1..=4).collect();
+///
+/// assert_eq!(split_comp_work(deque, |num: i64| num * 2), vec![2, 4, 6, 8]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_comp_work<I, R, F>(input: I, function: F) -> Vec<R>
+    where I: IntoIterator, I::Item: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(I::Item) -> R + Send + Sync {
+    let vector: Vec<I::Item> = input.into_iter().collect();
+    
+    // Below the threshold the global pool is neither used nor created
+    if vector.len() < THRESHOLD || is_pool_thread() {
+        return split_comp_work_with_config(vector, function, &SplitConfig::default());
+    }
+    
+    global_pool().map(vector, function)
+}
+
+/// Splits computational work between threads using the given threshold.
+///
+/// Works like [`split_comp_work`], but threads are spawned only if the vector has at least
+/// `threshold` items. A threshold of `1` means that the work is always split if there is
+/// more than one item, and a threshold larger than the length of the vector means that
+/// the work is done in the current thread.
+///
+/// # Panics
+///
+/// Panics if `threshold` is `0`.
+#[cfg(feature = "std")]
+pub fn split_comp_work_with_threshold<T, R, F>(vector: Vec<T>, function: F, threshold: usize) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_with_config(vector, function, &config_with_limits(threshold, default_max_threads()))
+}
+
+/// Splits computational work between at most `max_threads` threads.
+///
+/// Works like [`split_comp_work`], but the number of spawned threads is capped by
+/// `max_threads` instead of [`default_max_threads`]. A maximum of `1` means that the work
+/// is always done in the current thread, and a maximum of `0` means that
+/// [`default_max_threads`] is used.
+#[cfg(feature = "std")]
+pub fn split_comp_work_with_max_threads<T, R, F>(vector: Vec<T>, function: F, max_threads: usize) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    // Zero threads can't do any work, so the default maximum is used instead
+    let max_threads = if max_threads == 0 { default_max_threads() } else { max_threads };
+    
+    split_comp_work_with_config(vector, function, &config_with_limits(THRESHOLD, max_threads))
+}
+
+/// Splits computational work between threads and reports failures as errors.
+///
+/// Works like [`split_comp_work`], but if a thread doing computational work panics or
+/// can't send its results, [`SplitError::WorkerPanicked`] or [`SplitError::ResultsLost`]
+/// is returned instead of panicking. Panics of `function` on the current thread (for
+/// vectors shorter than [`THRESHOLD`]) are not caught.
+#[cfg(feature = "std")]
+pub fn split_comp_work_checked<T, R, F>(vector: Vec<T>, function: F) -> Result<Vec<R>, SplitError>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_checked_with_config(vector, function, &SplitConfig::default())
+}
+
+// Creating the configuration with the given threshold and maximum number of threads,
+// invalid values are rejected with a panic
+#[cfg(feature = "std")]
+fn config_with_limits(threshold: usize, max_threads: usize) -> SplitConfig {
+    SplitConfig::builder()
+        .threshold(threshold)
+        .max_threads(max_threads)
+        .build()
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Splits computational work between threads using the configuration.
+///
+/// Works like [`split_comp_work`], but the threshold, the maximum number of threads and
+/// the other settings are taken from `config`. The threads are spawned for this call
+/// instead of being taken from the global pool. It's the same as [`SplitConfig::run`] on a
+/// vector.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::{split_comp_work_with_config, SplitConfig};
+///
+/// let config = SplitConfig::builder().threshold(4).max_threads(2).build().unwrap();
+///
+/// let result = split_comp_work_with_config((1..=10).collect(), |num: i64| num * 2, &config);
+///
+/// assert_eq!(result, (1..=10).map(|num| num * 2).collect::<Vec<i64>>());
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+#[cfg(feature = "std")]
+pub fn split_comp_work_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_by_chunks(vector, function, config)
+        .unwrap_or_else(|failure| failure.raise())
+}
+
+// Splitting of computational work using the configuration, failures are returned as errors
+#[cfg(feature = "std")]
+pub(crate) fn split_comp_work_checked_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Result<Vec<R>, SplitError>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_by_chunks(vector, function, config)
+        .map_err(WorkFailure::into_error)
+}
+
+// Splitting of computational work using the configuration, every thread sends results of
+// its whole chunk at once. Failures of threads are returned as is
+#[cfg(feature = "std")]
+fn split_comp_work_by_chunks<T, R, F>(vector: Vec<T>, function: F, config: &SplitConfig)
+    -> Result<Vec<R>, WorkFailure>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    // Nothing to split, so no thread math is done whatever the configuration is
+    if vector.is_empty() {
+        return Ok(Vec::new());
+    }
+    
+    // Single-element vectors are mapped directly, the allocation of the vector is reused
+    // for the result if possible
+    if vector.len() == 1 {
+        return Ok(vector.into_iter().map(function).collect());
+    }
+    
+    if is_sequential(vector.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        return Ok(do_comp_work_in_cur_thread(vector, function));
+    }
+    
+    let len = vector.len();
+    
+    // Channel for transferring results of chunks of computational work
+    let (sender, receiver): (ResultSender<ChunkPair<R>>, Receiver<ChunkPair<R>>) = result_channel(config);
+    
+    let handles = spawn_chunk_workers(vector, config, sender, move |chunk, sender| {
+        do_chunk_work_in_some_thread(chunk, sender, &function)
+    })?;
+    
+    // Results of chunks are ordered by indices of their first items
+    let mut chunk_results: BTreeMap<usize, Vec<R>> = BTreeMap::new();
+    
+    for (start, chunk_result) in receiver {
+        chunk_results.insert(start, chunk_result);
+    }
+    
+    if let Some(failure) = first_failure(join_workers(handles)) {
+        return Err(failure);
+    }
+    
+    let mut result: Vec<R> = Vec::with_capacity(len);
+    
+    for chunk_result in chunk_results.into_values() {
+        result.extend(chunk_result);
+    }
+    
+    if result.len() < len {
+        return Err(WorkFailure::Error(SplitError::ResultsLost { missing: len - result.len() }));
+    }
+    
+    Ok(result)
+}
+
+// Splitting of computational work using the configuration, failures of threads are
+// returned as is. Progress is reported from the current thread only, and if the token is
+// cancelled the work stops between items. If the deadline passes, the token is cancelled
+// and threads which are still busy are left to finish their current items on their own
+#[cfg(feature = "std")]
+pub(crate) fn split_comp_work_in_threads<T, R, F, P>(vector: Vec<T>, function: F, config: &SplitConfig,
+    progress: &P, cancel: Option<&CancelToken>, deadline: Option<Instant>) -> Result<Vec<R>, WorkFailure>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync, P: Fn(usize, usize) {
+    
+    if is_sequential(vector.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        let len = vector.len();
+        let mut result: Vec<R> = Vec::with_capacity(len);
+        
+        for item in vector {
+            if is_cancelled(cancel) {
+                return Err(WorkFailure::Error(SplitError::Cancelled));
+            }
+            
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(WorkFailure::Error(SplitError::TimedOut { completed: result.len() }));
+            }
+            
+            result.push(function(item));
+            progress(result.len(), len);
+        }
+        
+        return Ok(result);
+    }
+    
+    let len = vector.len();
+    let (receiver, handles) = spawn_workers(vector, function, config, cancel)?;
+    
+    let mut workers = handles.into_iter();
+    
+    match collect_results_with_progress(receiver, join_workers(&mut workers), len, progress, deadline) {
+        
+        // Threads are stopped at their next items, finished threads are joined and busy
+        // ones are detached
+        Err(WorkFailure::Error(SplitError::TimedOut { completed })) => {
+            if let Some(cancel) = cancel {
+                cancel.cancel();
+            }
+            
+            workers.filter(|(_, _, handle)| handle.is_finished()).for_each(|(_, _, handle)| drop(handle.join()));
+            
+            Err(WorkFailure::Error(SplitError::TimedOut { completed }))
+        }
+        
+        // Results haven't been sent by threads since the work has been cancelled
+        Err(WorkFailure::Error(SplitError::ResultsLost { .. })) if is_cancelled(cancel) => {
+            Err(WorkFailure::Error(SplitError::Cancelled))
+        }
+        outcome => outcome,
+    }
+}
+
+// Spawning threads for computational work on chunks of the vector, results are sent
+// through the returned receiver. If a thread can't be spawned, already spawned threads
+// are joined
+#[cfg(feature = "std")]
+pub(crate) fn spawn_workers<T, R, F>(vector: Vec<T>, function: F, config: &SplitConfig,
+    cancel: Option<&CancelToken>) -> Result<(Receiver<OutputPair<R>>, Vec<WorkerHandle>), WorkFailure>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    // Channel for transferring results of computational work, every result is sent as
+    // `OutputPair` with the index of its input item, so the results can be put back into
+    // input order whatever order they arrive in
+    let (sender, receiver): (ResultSender<OutputPair<R>>, Receiver<OutputPair<R>>) = result_channel(config);
+    
+    let cancel = cancel.cloned();
+    
+    let handles = spawn_chunk_workers(vector, config, sender, move |chunk, sender| {
+        do_comp_work_in_some_thread(chunk, sender, &function, cancel.as_ref())
+    })?;
+    
+    Ok((receiver, handles))
+}
+
+// Spawning threads for `worker` on chunks of the vector, every thread gets its chunk
+// together with indices of the items and a clone of the sender. If a thread can't be
+// spawned, its chunk and all next chunks are processed in the current thread. Only if the
+// channel is bounded, the failure is returned, because nobody receives results yet
+#[cfg(feature = "std")]
+fn spawn_chunk_workers<T, M, W>(vector: Vec<T>, config: &SplitConfig, sender: ResultSender<M>, worker: W)
+    -> Result<Vec<WorkerHandle>, WorkFailure>
+    where T: 'static + Send, M: 'static + Send,
+    W: 'static + Fn(ChunkItems<T>, ResultSender<M>) -> Result<(), SplitError> + Send + Sync {
+    let len = vector.len();
+    let mut items = vector.into_iter();
+    
+    // Worker shared between threads
+    let worker = Arc::new(worker);
+    
+    // Handles of spawned threads
+    let mut handles: Vec<WorkerHandle> = Vec::new();
+    
+    // Whether a thread has failed to spawn, then no more threads are tried
+    let mut spawning_failed = false;
+    
+    // Spawning threads for computational work
+    for (i, range) in chunk_ranges(len, config).into_iter().enumerate() {
+        
+        // Moving items of the slice of the vector into the chunk of the thread
+        let chunk: Vec<T> = (&mut items).take(range.len()).collect();
+        let chunk_items = range.clone().zip(chunk);
+        
+        if !spawning_failed {
+            
+            // The chunk is handed over after spawning, so it isn't lost if spawning fails
+            let (chunk_sender, chunk_receiver) = mpsc::channel::<ChunkItems<T>>();
+            let sender_copy = sender.clone();
+            let worker_copy = Arc::clone(&worker);
+            
+            let spawned = config.worker_builder(i).spawn(move || match chunk_receiver.recv() {
+                Ok(chunk_items) => worker_copy(chunk_items, sender_copy),
+                Err(_) => Ok(()),
+            });
+            
+            match spawned {
+                Ok(handle) => {
+                    chunk_sender.send(chunk_items).expect("Thread has stopped before getting its chunk");
+                    handles.push((i, range, WorkerJoin::Thread(handle)));
+                    
+                    // Logging debugging information
+                    trace!("Thread {} has spawned", i);
+                    
+                    continue;
+                }
+                
+                // Spawned threads are detached, they stop when the receiver is dropped
+                Err(error) if matches!(sender, ResultSender::Bounded(_)) => {
+                    return Err(WorkFailure::Error(SplitError::SpawnFailed(error)));
+                }
+                Err(error) => {
+                    spawning_failed = true;
+                    
+                    // Logging debugging information
+                    debug!("Thread {} has failed to spawn ({}), doing its work in the current thread", i, error);
+                }
+            }
+        }
+        
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| worker(chunk_items, sender.clone())));
+        
+        handles.push((i, range, WorkerJoin::Inline(outcome)));
+    }
+    
+    // Releasing the first non-used sender
+    drop(sender);
+    
+    Ok(handles)
+}
+
+// Joining threads lazily in order of their chunks
+#[cfg(feature = "std")]
+pub(crate) fn join_workers<I>(handles: I) -> impl Iterator<Item = WorkerOutcome>
+    where I: IntoIterator<Item = WorkerHandle> {
+    handles.into_iter().map(|(chunk, range, handle)| (chunk, range, handle.join()))
+}
+
+// Checking whether the work should be done in the current thread
+#[cfg(feature = "std")]
+pub(crate) fn is_sequential(len: usize, config: &SplitConfig) -> bool {
+    
+    // If length of the vector less than the threshold or only one thread is allowed
+    // then no threads are needed
+    config.force_sequential() || len < config.threshold() || len <= 1 || config.max_threads() == 1
+}
+
+// Splitting indices of the vector into chunks using the configuration, one chunk per thread
+#[cfg(feature = "std")]
+pub(crate) fn chunk_ranges(len: usize, config: &SplitConfig) -> Vec<Range<usize>> {
+    partition(len, config.threshold(), config.max_threads())
+}
+
+/// Splits indices `0..len` into ranges, one range per thread.
+///
+/// This is the partitioning used by [`split_comp_work`] and the other functions of the
+/// crate, so it can be reused by custom parallel code. One thread is planned per
+/// `threshold` items, with at most `max_threads` threads. The ranges are contiguous and in
+/// order, their sizes differ by at most one item, and no range is empty. No ranges are
+/// returned if `len` or `max_threads` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::partition;
+///
+/// assert_eq!(partition(10, 4, 8), vec![0..4, 4..7, 7..10]);
+/// assert_eq!(partition(10, 4, 2), vec![0..5, 5..10]);
+/// ```
+pub fn partition(len: usize, threshold: usize, max_threads: usize) -> Vec<Range<usize>> {
+    
+    // Number of threads, a zero threshold plans as many threads as possible
+    let num_of_threads = match (len, threshold) {
+        (0, _) => 0,
+        (_, 0) => max_threads,
+        _ => len.div_ceil(threshold),
+    };
+    
+    // Number of threads can't be more than maximum number of threads
+    let num_of_threads = num_of_threads.min(max_threads);
+    
+    if num_of_threads == 0 {
+        return Vec::new();
+    }
+    
+    // Number of items per one thread, the first threads get one item more until the
+    // remainder is used up
+    let items_per_thread = len / num_of_threads;
+    let remainder = len % num_of_threads;
+    
+    let mut ranges: Vec<Range<usize>> = Vec::with_capacity(num_of_threads);
+    let mut index_of_cur_item: usize = 0;
+    
+    for i in 0..num_of_threads {
+        let end_index = index_of_cur_item + items_per_thread + usize::from(i < remainder);
+        
+        ranges.push(index_of_cur_item..end_index);
+        
+        index_of_cur_item = end_index;
+    }
+    
+    ranges
+}
+
+/// Work on one chunk of the vector, which returns the results of the chunk in order
+pub type Job<'a, R> = Box<dyn FnOnce() -> Vec<R> + Send + 'a>;
+
+/// Splits computational work into jobs and runs them on a custom executor.
+///
+/// The vector is split using [`partition`] and every chunk becomes a [`Job`] which applies
+/// the function to the items of the chunk. The jobs are passed to `execute` in the order
+/// of the chunks, and `execute` must return the results of every job in the same order.
+/// The jobs can be run on any executor, for example on the threads of an embedded RTOS, so
+/// this function is available without the `std` feature. No jobs are created for an empty
+/// vector or a zero `max_threads`.
+///
+/// # Panics
+///
+/// Panics if `execute` doesn't return exactly one result vector per job.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::execute_with;
+///
+/// // Running the jobs one after another
+/// let results = execute_with(vec![1, 2, 3, 4, 5], 2, 4, &|x: i32| x * 10,
+///     |jobs| jobs.into_iter().map(|job| job()).collect());
+///
+/// assert_eq!(results, vec![10, 20, 30, 40, 50]);
+/// ```
+pub fn execute_with<'a, T, R, F, E>(vector: Vec<T>, threshold: usize, max_threads: usize, function: &'a F,
+    execute: E) -> Vec<R>
+    where T: Send + 'a, F: Fn(T) -> R + Sync, E: FnOnce(Vec<Job<'a, R>>) -> Vec<Vec<R>> {
+    
+    let len = vector.len();
+    let ranges = partition(len, threshold, max_threads);
+    let num_of_jobs = ranges.len();
+    
+    let mut items = vector.into_iter();
+    let mut jobs: Vec<Job<'a, R>> = Vec::with_capacity(num_of_jobs);
+    
+    for range in ranges {
+        let chunk: Vec<T> = items.by_ref().take(range.len()).collect();
+        
+        jobs.push(Box::new(move || chunk.into_iter().map(function).collect()));
+    }
+    
+    let chunk_results = execute(jobs);
+    
+    assert_eq!(chunk_results.len(), num_of_jobs,
+        "Executor returned {} results for {} jobs", chunk_results.len(), num_of_jobs);
+    
+    let mut results: Vec<R> = Vec::with_capacity(len);
+    
+    for chunk in chunk_results {
+        results.extend(chunk);
+    }
+    
+    results
+}
+
+// Receiving results from threads and checking that all threads have done their work,
+// outcomes of threads are awaited only after all results are received
+#[cfg(feature = "std")]
+pub(crate) fn collect_results<R, O>(receiver: Receiver<OutputPair<R>>, outcomes: O, len: usize)
+    -> Result<Vec<R>, WorkFailure>
+    where O: IntoIterator<Item = WorkerOutcome> {
+    collect_results_with_progress(receiver, outcomes, len, &|_, _| {}, None)
+}
+
+// Receiving results from threads like `collect_results`, the number of received results
+// is reported after every result. If the deadline passes, outcomes aren't awaited
+#[cfg(feature = "std")]
+pub(crate) fn collect_results_with_progress<R, O, P>(receiver: Receiver<OutputPair<R>>, outcomes: O,
+    len: usize, progress: &P, deadline: Option<Instant>) -> Result<Vec<R>, WorkFailure>
+    where O: IntoIterator<Item = WorkerOutcome>, P: Fn(usize, usize) {
+    let mut result: Vec<Option<R>> = Vec::new();
+    result.resize_with(len, || None);
+    
+    let mut num_of_received: usize = 0;
+    
+    // Receiving results
+    loop {
+        let received = match deadline {
+            None => match receiver.recv() {
+                Ok(received) => received,
+                Err(_) => break,
+            },
+            Some(deadline) => match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(received) => received,
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(WorkFailure::Error(SplitError::TimedOut { completed: num_of_received }));
+                }
+            },
+        };
+        
+        let (index, item) = received;
+        result[index] = Some(item);
+        
+        num_of_received += 1;
+        progress(num_of_received, len);
+    }
+    
+    // Checking that all threads have done their work
+    if let Some(failure) = first_failure(outcomes) {
+        return Err(failure);
+    }
+    
+    let missing = result.iter().filter(|item| item.is_none()).count();
+    
+    if missing > 0 {
+        return Err(WorkFailure::Error(SplitError::ResultsLost { missing }));
+    }
+    
+    Ok(result.into_iter().flatten().collect())
+}
+
+// Awaiting all outcomes of threads, the first failure is kept
+#[cfg(feature = "std")]
+pub(crate) fn first_failure<O>(outcomes: O) -> Option<WorkFailure>
+    where O: IntoIterator<Item = WorkerOutcome> {
+    let mut failure: Option<WorkFailure> = None;
+    
+    for (chunk, range, outcome) in outcomes {
+        let thread_failure = match outcome {
+            Ok(Ok(())) => continue,
+            Ok(Err(error)) => WorkFailure::Error(error),
+            Err(payload) => WorkFailure::Panic(chunk, range, payload),
+        };
+        
+        failure.get_or_insert(thread_failure);
+    }
+    
+    failure
+}
+
+// Doing computational work in current thread
+#[cfg(feature = "std")]
+pub(crate) fn do_comp_work_in_cur_thread<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where F: Fn(T) -> R {
+    let mut result: Vec<R> = Vec::with_capacity(vector.len());
+    
+    for item in vector {
+        result.push(function(item));
+    }
+    
+    result
+}
+
+// Doing computational work in some thread, the rest of the chunk is skipped if the token
+// is cancelled
+#[cfg(feature = "std")]
+pub(crate) fn do_comp_work_in_some_thread<T, R, F, I>(vector: I, sender: ResultSender<OutputPair<R>>,
+    function: &F, cancel: Option<&CancelToken>) -> Result<(), SplitError>
+    where F: Fn(T) -> R, I: IntoIterator<Item = InputPair<T>>, I::IntoIter: ExactSizeIterator {
+    let vector = vector.into_iter();
+    let len = vector.len();
+    
+    for (num_of_sent, (index, item)) in vector.enumerate() {
+        if is_cancelled(cancel) {
+            break;
+        }
+        
+        let result = (index, function(item));
+        
+        // Sending result, the rest of the chunk is lost if the receiving side is closed
+        sender.send(result).map_err(|_| SplitError::ResultsLost { missing: len - num_of_sent })?;
+    }
+    
+    Ok(())
+}
+
+// Doing computational work on the whole chunk in some thread, results are sent at once
+// together with the index of the first item
+#[cfg(feature = "std")]
+fn do_chunk_work_in_some_thread<T, R, F>(chunk: ChunkItems<T>, sender: ResultSender<ChunkPair<R>>, function: &F)
+    -> Result<(), SplitError> where F: Fn(T) -> R {
+    let len = chunk.len();
+    let mut start: Option<usize> = None;
+    let mut result: Vec<R> = Vec::with_capacity(len);
+    
+    for (index, item) in chunk {
+        start.get_or_insert(index);
+        result.push(function(item));
+    }
+    
+    match start {
+        Some(start) => sender.send((start, result)).map_err(|_| SplitError::ResultsLost { missing: len }),
+        None => Ok(()),
+    }
+}
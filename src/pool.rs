@@ -0,0 +1,265 @@
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, mpsc::Receiver, mpsc::Sender, Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{chunk_ranges, default_max_threads, do_comp_work_in_cur_thread, is_sequential, result_channel, ResultSender,
+    SplitConfig, THRESHOLD};
+
+// Job run by a thread of the pool
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+// Results of a chunk together with the index of the chunk, or the panic of its job
+type ChunkOutcome<R> = (usize, thread::Result<Vec<R>>);
+
+// Pool used by `split_comp_work`, created on first use
+static GLOBAL_POOL: OnceLock<WorkPool> = OnceLock::new();
+
+thread_local! {
+    
+    // Whether the current thread belongs to a pool
+    static IS_POOL_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Pool of threads reused by many calls of splitting of computational work.
+///
+/// Threads are spawned once by [`WorkPool::new`] and wait for jobs while idle, so calling
+/// [`WorkPool::map`] many times doesn't pay for spawning threads. Every call has its own
+/// channel for results, so the pool can be used by several threads at once. Threads are
+/// stopped and joined when the pool is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::WorkPool;
+///
+/// let pool = WorkPool::new(4);
+///
+/// for _ in 0..10 {
+///     let result = pool.map((0..100).collect(), |num: i64| num * 2);
+///
+///     assert_eq!(result, (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+/// }
+/// ```
+pub struct WorkPool {
+    config: SplitConfig,
+    job_sender: Option<Sender<Job>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+/// Another name of [`WorkPool`], so it's created and used the same way.
+pub type WorkerPool = WorkPool;
+
+impl WorkPool {
+    
+    /// Creates a pool of `num_of_threads` threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_of_threads` is `0`.
+    pub fn new(num_of_threads: usize) -> Self {
+        let config = SplitConfig::builder()
+            .threshold(THRESHOLD)
+            .max_threads(num_of_threads)
+            .build()
+            .unwrap_or_else(|error| panic!("{}", error));
+        
+        // Channel for transferring jobs to threads of the pool
+        let (job_sender, job_receiver): (Sender<Job>, Receiver<Job>) = mpsc::channel();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        
+        let threads = (0..num_of_threads).map(|i| {
+            let job_receiver_copy = Arc::clone(&job_receiver);
+            
+            // Already spawned threads stop when the sender of jobs is dropped by the panic
+            config.worker_builder(i)
+                .spawn(move || run_jobs(&job_receiver_copy))
+                .unwrap_or_else(|error| WorkFailure::Error(SplitError::SpawnFailed(error)).raise())
+        }).collect();
+        
+        WorkPool { config, job_sender: Some(job_sender), threads }
+    }
+    
+    /// Number of threads of the pool
+    pub fn num_of_threads(&self) -> usize {
+        self.threads.len()
+    }
+    
+    /// Splits computational work between threads of the pool.
+    ///
+    /// Works like [`split_comp_work`](crate::split_comp_work), but the chunks are processed
+    /// by threads of the pool, with at most one chunk per thread. If `function` panics, the
+    /// panic is resumed in the calling thread and the pool stays usable.
+    pub fn map<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        
+        if is_sequential(vector.len(), &self.config) {
+            return do_comp_work_in_cur_thread(vector, function);
+        }
+        
+        // Channel for transferring results of whole chunks of this call, so there is one
+        // message per job instead of one per item
+        let (sender, receiver): (ResultSender<ChunkOutcome<R>>, Receiver<ChunkOutcome<R>>) =
+            result_channel(&self.config);
+        
+        let len = vector.len();
+        let mut items = vector.into_iter();
+        let function = Arc::new(function);
+        let ranges = chunk_ranges(len, &self.config);
+        let num_of_jobs = ranges.len();
+        
+        for (i, range) in ranges.into_iter().enumerate() {
+            
+            // Moving items of the slice of the vector into the chunk of the job
+            let chunk: Vec<T> = items.by_ref().take(range.len()).collect();
+            
+            let sender_copy = sender.clone();
+            let function_copy = Arc::clone(&function);
+            
+            self.submit(Box::new(move || {
+                let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                    chunk.into_iter().map(&*function_copy).collect::<Vec<R>>()
+                }));
+                
+                // Caller may have stopped waiting if it has panicked itself
+                let _ = sender_copy.send((i, outcome));
+            }));
+        }
+        
+        // Releasing the first non-used sender
+        drop(sender);
+        
+        // Results are put in order of chunks, all jobs are awaited before a panic is resumed
+        let mut chunk_results: Vec<Option<Vec<R>>> = (0..num_of_jobs).map(|_| None).collect();
+        let mut panic_payload = None;
+        
+        for (i, outcome) in receiver.iter().take(num_of_jobs) {
+            match outcome {
+                Ok(chunk_result) => chunk_results[i] = Some(chunk_result),
+                Err(payload) => {
+                    panic_payload.get_or_insert(payload);
+                }
+            }
+        }
+        
+        if let Some(payload) = panic_payload {
+            panic::resume_unwind(payload);
+        }
+        
+        let mut result: Vec<R> = Vec::with_capacity(len);
+        
+        for chunk_result in chunk_results {
+            result.extend(chunk_result.expect("Thread of the pool has stopped"));
+        }
+        
+        result
+    }
+    
+    /// Splits computational work between threads of the pool, the same as
+    /// [`WorkPool::map`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use splitting_of_work::WorkerPool;
+    ///
+    /// let pool = WorkerPool::new(2);
+    ///
+    /// assert_eq!(pool.split_comp_work((1..=10).collect(), |num: i64| num * 3)[9], 30);
+    /// ```
+    pub fn split_comp_work<T, R, F>(&self, vector: Vec<T>, function: F) -> Vec<R>
+        where T: 'static + Send, R: 'static + Send,
+        F: 'static + Fn(T) -> R + Send + Sync {
+        self.map(vector, function)
+    }
+    
+    // Sending the job to threads of the pool
+    fn submit(&self, job: Job) {
+        self.job_sender
+            .as_ref()
+            .expect("Pool has been stopped")
+            .send(job)
+            .expect("Can't send job to threads of the pool");
+    }
+}
+
+impl Drop for WorkPool {
+    fn drop(&mut self) {
+        
+        // Closing the channel of jobs stops threads after they finish their jobs
+        drop(self.job_sender.take());
+        
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Creates the global pool used by [`split_comp_work`](crate::split_comp_work) with
+/// `num_of_threads` threads.
+///
+/// Without this call the global pool is created on the first call of
+/// [`split_comp_work`](crate::split_comp_work) which needs threads, with
+/// [`default_max_threads`](crate::default_max_threads) threads. Calls from several threads
+/// at once are safe, only one of them creates the pool.
+///
+/// # Errors
+///
+/// Returns [`SplitError::PoolAlreadyInitialized`] if the global pool has already been
+/// created, by this function or by the first use.
+///
+/// # Panics
+///
+/// Panics if `num_of_threads` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::{init_global_pool, split_comp_work};
+///
+/// init_global_pool(4).unwrap();
+///
+/// assert!(init_global_pool(8).is_err());
+/// assert_eq!(split_comp_work((1..=34).collect::<Vec<i64>>(), |num: i64| num * 2)[33], 68);
+/// ```
+pub fn init_global_pool(num_of_threads: usize) -> Result<(), SplitError> {
+    let mut created = false;
+    
+    GLOBAL_POOL.get_or_init(|| {
+        created = true;
+        WorkPool::new(num_of_threads)
+    });
+    
+    if created {
+        Ok(())
+    } else {
+        Err(SplitError::PoolAlreadyInitialized)
+    }
+}
+
+// Getting the global pool, creating it with the default number of threads on first use
+pub(crate) fn global_pool() -> &'static WorkPool {
+    GLOBAL_POOL.get_or_init(|| WorkPool::new(default_max_threads()))
+}
+
+// Checking whether the current thread belongs to a pool, work submitted from such a thread
+// to a pool could wait for itself
+pub(crate) fn is_pool_thread() -> bool {
+    IS_POOL_THREAD.with(Cell::get)
+}
+
+// Running jobs in a thread of the pool until the channel of jobs is closed
+fn run_jobs(job_receiver: &Mutex<Receiver<Job>>) {
+    IS_POOL_THREAD.with(|is_pool_thread| is_pool_thread.set(true));
+    
+    loop {
+        let job = job_receiver.lock().expect("Channel of jobs is poisoned").recv();
+        
+        match job {
+            Ok(job) => job(),
+            Err(_) => break,
+        }
+    }
+}
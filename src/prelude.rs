@@ -0,0 +1,7 @@
+//! Traits for splitting of computational work in method-chaining style
+//!
+//! ```
+//! use splitting_of_work::prelude::*;
+//! ```
+
+pub use crate::ext::{ParallelSplit, SplitMapExt};
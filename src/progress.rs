@@ -0,0 +1,48 @@
+use crate::{split_comp_work_in_threads, SplitConfig};
+
+/// Splits computational work between threads and reports progress.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but `progress` is called with
+/// the number of completed items and the total number of items every time a result is
+/// received. It is called only from the calling thread, both when threads are spawned and
+/// when the work is done in the current thread, so it doesn't have to be `Send` or `Sync`,
+/// and the completed count grows by one on every call. The callback only observes the
+/// work: results are returned in input order and none of them are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use splitting_of_work::split_comp_work_with_progress;
+///
+/// let completed = Cell::new(0);
+///
+/// let result = split_comp_work_with_progress((1..=34).collect(), |num: i64| num * 2,
+///     |done, total| {
+///         assert_eq!(total, 34);
+///         completed.set(done);
+///     });
+///
+/// assert_eq!(result, (1..=34).map(|num| num * 2).collect::<Vec<i64>>());
+/// assert_eq!(completed.get(), 34);
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_work_with_progress<T, R, F, P>(vector: Vec<T>, function: F, progress: P) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync, P: Fn(usize, usize) {
+    split_comp_work_with_progress_with_config(vector, function, progress, &SplitConfig::default())
+}
+
+// Splitting of computational work with reporting of progress using the configuration
+pub(crate) fn split_comp_work_with_progress_with_config<T, R, F, P>(vector: Vec<T>, function: F,
+    progress: P, config: &SplitConfig) -> Vec<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync, P: Fn(usize, usize) {
+    split_comp_work_in_threads(vector, function, config, &progress, None, None)
+        .unwrap_or_else(|failure| failure.raise())
+}
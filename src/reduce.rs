@@ -0,0 +1,77 @@
+use crate::scoped::run_in_chunks;
+use crate::{is_sequential, SplitConfig};
+
+/// Maps and reduces the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work). Every
+/// thread maps the items of its chunk with `map` and folds them with `combine` starting
+/// from a clone of `identity`, then the partial results are combined in order of chunks in
+/// the calling thread. No vector of mapped items is materialized.
+///
+/// `combine` must be associative and `identity` must be its identity element, otherwise
+/// the result depends on how the vector is split.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_reduce;
+///
+/// let sum = split_reduce((1..=100).collect(), |num: i64| num * num, |a, b| a + b, 0);
+///
+/// assert_eq!(sum, 338_350);
+/// ```
+pub fn split_reduce<T, R, M, C>(vector: Vec<T>, map: M, combine: C, identity: R) -> R
+    where T: Send, R: Clone + Send + Sync, M: Fn(T) -> R + Sync, C: Fn(R, R) -> R + Sync {
+    split_reduce_with_config(vector, map, combine, identity, &SplitConfig::default())
+}
+
+// Mapping and reducing of the vector in parallel using the configuration
+pub(crate) fn split_reduce_with_config<T, R, M, C>(vector: Vec<T>, map: M, combine: C, identity: R,
+    config: &SplitConfig) -> R
+    where T: Send, R: Clone + Send + Sync, M: Fn(T) -> R + Sync, C: Fn(R, R) -> R + Sync {
+    let partials = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().map(&map).fold(identity.clone(), &combine)
+    });
+    
+    partials.into_iter().fold(identity, combine)
+}
+
+/// Folds the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work). Every
+/// thread folds the items of its chunk with `fold` starting from a clone of `identity`,
+/// then the accumulators of the chunks are combined with `combine` in order of chunks in
+/// the calling thread. If the work is done in the current thread, the vector is just
+/// folded.
+///
+/// `combine` must be associative and `identity` must be its identity element, otherwise
+/// the result depends on how the vector is split.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_fold;
+///
+/// let longest = split_comp_fold(vec!["a", "abc", "ab"], 0, |max, item: &str| max.max(item.len()), usize::max);
+///
+/// assert_eq!(longest, 3);
+/// ```
+pub fn split_comp_fold<T, A, F, C>(vector: Vec<T>, identity: A, fold: F, combine: C) -> A
+    where T: Send, A: Clone + Send + Sync, F: Fn(A, T) -> A + Sync, C: Fn(A, A) -> A {
+    split_comp_fold_with_config(vector, identity, fold, combine, &SplitConfig::default())
+}
+
+// Folding of the vector in parallel using the configuration
+pub(crate) fn split_comp_fold_with_config<T, A, F, C>(vector: Vec<T>, identity: A, fold: F, combine: C,
+    config: &SplitConfig) -> A
+    where T: Send, A: Clone + Send + Sync, F: Fn(A, T) -> A + Sync, C: Fn(A, A) -> A {
+    if is_sequential(vector.len(), config) {
+        return vector.into_iter().fold(identity, fold);
+    }
+    
+    let accumulators = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().fold(identity.clone(), &fold)
+    });
+    
+    accumulators.into_iter().fold(identity, combine)
+}
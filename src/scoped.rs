@@ -0,0 +1,239 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread::{self, Scope, ScopedJoinHandle};
+
+use log::{debug, trace};
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{chunk_ranges, is_sequential, SplitConfig};
+
+/// Splits computational work on a borrowed slice between scoped threads.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the items are borrowed
+/// instead of moved, so neither the items nor `function` have to be `'static`. Every
+/// thread gets its own sub-slice of `slice`, and all threads are joined before the
+/// function returns.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_ref;
+///
+/// let words: Vec<String> = (0..100).map(|num| num.to_string()).collect();
+///
+/// let lengths = split_comp_work_ref(&words, |word: &String| word.len());
+///
+/// assert_eq!(lengths[99], 2);
+/// assert_eq!(words.len(), 100);
+/// ```
+pub fn split_comp_work_ref<T, R, F>(slice: &[T], function: F) -> Vec<R>
+    where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    split_comp_work_ref_with_config(slice, function, &SplitConfig::default())
+}
+
+/// Splits computational work between scoped threads.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), including the splitting by
+/// [`THRESHOLD`](crate::THRESHOLD) and the order of results, but neither the items nor
+/// `function` have to be `'static`, so `function` can borrow data from the stack of the
+/// caller. All threads are joined before the function returns.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_scoped;
+///
+/// let table: Vec<i64> = (0..100).map(|num| num * num).collect();
+/// let lookup: &[i64] = &table;
+///
+/// let result = split_comp_work_scoped((0..100).collect(), |index: usize| lookup[index]);
+///
+/// assert_eq!(result, table);
+/// ```
+pub fn split_comp_work_scoped<T, R, F>(vector: Vec<T>, function: F) -> Vec<R>
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    split_comp_work_scoped_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work between scoped threads using the configuration
+pub(crate) fn split_comp_work_scoped_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    let len = vector.len();
+    
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().map(&function).collect::<Vec<R>>()
+    });
+    
+    concat_in_order(chunk_results, len)
+}
+
+// Splitting of computational work on a borrowed slice using the configuration
+pub(crate) fn split_comp_work_ref_with_config<T, R, F>(slice: &[T], function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    let chunk_results = run_on_slices(slice, config, |_, chunk: &[T]| {
+        chunk.iter().map(&function).collect::<Vec<R>>()
+    });
+    
+    concat_in_order(chunk_results, slice.len())
+}
+
+// Doing computational work on chunks of the vector in scoped threads, the worker gets
+// index of the first item of the chunk and the chunk itself. Results of the chunks are
+// returned in order of chunks. If no threads are needed, the whole vector is one chunk
+// processed in the current thread
+pub(crate) fn run_in_chunks<T, C, W>(vector: Vec<T>, config: &SplitConfig, worker: W) -> Vec<C>
+    where T: Send, C: Send, W: Fn(usize, Vec<T>) -> C + Sync {
+    
+    if is_sequential(vector.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        return vec![worker(0, vector)];
+    }
+    
+    let len = vector.len();
+    let mut items = vector.into_iter();
+    let worker = &worker;
+    
+    thread::scope(|scope| {
+        
+        // Spawning threads for computational work
+        let handles: Vec<_> = chunk_ranges(len, config).into_iter().enumerate().map(|(i, range)| {
+            
+            // Moving items of the slice of the vector into the chunk of the thread
+            let chunk: Vec<T> = (&mut items).take(range.len()).collect();
+            
+            let start = range.start;
+            spawn_or_run(scope, config, i, chunk, move |chunk| worker(start, chunk))
+        }).collect();
+        
+        join_in_order(handles)
+    })
+}
+
+// Doing computational work on sub-slices of the slice in scoped threads, the same way
+// as `run_in_chunks` does it for chunks of the vector
+pub(crate) fn run_on_slices<T, C, W>(slice: &[T], config: &SplitConfig, worker: W) -> Vec<C>
+    where T: Sync, C: Send, W: Fn(usize, &[T]) -> C + Sync {
+    
+    if is_sequential(slice.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        return vec![worker(0, slice)];
+    }
+    
+    let worker = &worker;
+    
+    thread::scope(|scope| {
+        
+        // Spawning threads for computational work
+        let handles: Vec<_> = chunk_ranges(slice.len(), config).into_iter().enumerate().map(|(i, range)| {
+            let start = range.start;
+            let chunk = &slice[range];
+            
+            spawn_or_run(scope, config, i, chunk, move |chunk| worker(start, chunk))
+        }).collect();
+        
+        join_in_order(handles)
+    })
+}
+
+// Spawning the scoped thread with the given number using the configuration, for threads
+// which are fed while the current thread goes on, so their work can't be done inline. If the
+// thread can't be spawned, the failure is raised and already spawned threads are joined by
+// the scope
+pub(crate) fn spawn_scoped_worker<'scope, C, W>(scope: &'scope Scope<'scope, '_>, config: &SplitConfig, i: usize,
+    worker: W) -> ScopedJoinHandle<'scope, C>
+    where C: Send + 'scope, W: FnOnce() -> C + Send + 'scope {
+    config.worker_builder(i)
+        .spawn_scoped(scope, worker)
+        .unwrap_or_else(|error| WorkFailure::Error(SplitError::SpawnFailed(error)).raise())
+}
+
+// Work on a chunk in a scoped thread, or in the current thread if the thread couldn't be
+// spawned
+pub(crate) enum ScopedWorker<'scope, C> {
+    Thread(ScopedJoinHandle<'scope, C>),
+    Inline(thread::Result<C>),
+}
+
+impl<C> ScopedWorker<'_, C> {
+    
+    // Waiting for the result of the work
+    pub(crate) fn join(self) -> thread::Result<C> {
+        match self {
+            ScopedWorker::Thread(handle) => handle.join(),
+            ScopedWorker::Inline(outcome) => outcome,
+        }
+    }
+}
+
+// Spawning the scoped thread with the given number for `worker` on `input`. The input is
+// handed over after spawning, so if the thread can't be spawned, `worker` is called on it
+// in the current thread instead
+pub(crate) fn spawn_or_run<'scope, A, C, W>(scope: &'scope Scope<'scope, '_>, config: &SplitConfig, i: usize,
+    input: A, worker: W) -> ScopedWorker<'scope, C>
+    where A: Send + 'scope, C: Send + 'scope, W: FnOnce(A) -> C + Copy + Send + 'scope {
+    let (input_sender, input_receiver) = mpsc::channel::<A>();
+    
+    let spawned = config.worker_builder(i).spawn_scoped(scope, move || {
+        worker(input_receiver.recv().expect("Input of the thread is lost"))
+    });
+    
+    match spawned {
+        Ok(handle) => {
+            input_sender.send(input).unwrap_or_else(|_| panic!("Thread {} has stopped before getting its input", i));
+            
+            // Logging debugging information
+            trace!("Thread {} has spawned", i);
+            
+            ScopedWorker::Thread(handle)
+        }
+        Err(error) => {
+            
+            // Logging debugging information
+            debug!("Thread {} has failed to spawn ({}), doing its work in the current thread", i, error);
+            trace!("Work of thread {} is running inline", i);
+            
+            ScopedWorker::Inline(panic::catch_unwind(AssertUnwindSafe(|| worker(input))))
+        }
+    }
+}
+
+// Receiving results of scoped threads in order of chunks, the first panic is resumed
+// after all threads have finished
+pub(crate) fn join_in_order<C>(workers: Vec<ScopedWorker<'_, C>>) -> Vec<C> {
+    let mut result: Vec<C> = Vec::with_capacity(workers.len());
+    let mut panic_payload = None;
+    
+    for worker in workers {
+        match worker.join() {
+            Ok(chunk_result) => result.push(chunk_result),
+            Err(payload) => {
+                panic_payload.get_or_insert(payload);
+            }
+        }
+    }
+    
+    if let Some(payload) = panic_payload {
+        panic::resume_unwind(payload);
+    }
+    
+    result
+}
+
+// Concatenating results of chunks in order of chunks
+pub(crate) fn concat_in_order<R>(chunk_results: Vec<Vec<R>>, len: usize) -> Vec<R> {
+    let mut result: Vec<R> = Vec::with_capacity(len);
+    
+    for chunk_result in chunk_results {
+        result.extend(chunk_result);
+    }
+    
+    result
+}
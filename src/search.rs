@@ -0,0 +1,209 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::scoped::run_in_chunks;
+use crate::SplitConfig;
+
+/// Checks in parallel whether any item of the vector satisfies the predicate.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work). As
+/// soon as one thread finds an item satisfying `predicate`, all threads stop before their
+/// next items. `false` is returned for an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_any;
+///
+/// assert!(split_comp_any((1..=100).collect(), |num: &i64| *num == 77));
+/// assert!(!split_comp_any(Vec::new(), |num: &i64| *num == 77));
+/// ```
+pub fn split_comp_any<T, P>(vector: Vec<T>, predicate: P) -> bool
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_any_with_config(vector, predicate, &SplitConfig::default())
+}
+
+// Checking in parallel whether any item satisfies the predicate using the configuration
+pub(crate) fn split_comp_any_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig) -> bool
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    
+    // Whether some thread has found a satisfying item
+    let found = AtomicBool::new(false);
+    
+    run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        for item in chunk {
+            if found.load(Ordering::Relaxed) {
+                return;
+            }
+            
+            if predicate(&item) {
+                found.store(true, Ordering::Relaxed);
+                return;
+            }
+        }
+    });
+    
+    found.into_inner()
+}
+
+/// Checks in parallel whether all items of the vector satisfy the predicate.
+///
+/// Works like [`split_comp_any`], but all threads stop as soon as one of them finds an
+/// item which doesn't satisfy `predicate`. `true` is returned for an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_all;
+///
+/// assert!(split_comp_all((1..=100).collect(), |num: &i64| *num > 0));
+/// assert!(split_comp_all(Vec::new(), |num: &i64| *num > 1000));
+/// ```
+pub fn split_comp_all<T, P>(vector: Vec<T>, predicate: P) -> bool
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_all_with_config(vector, predicate, &SplitConfig::default())
+}
+
+// Checking in parallel whether all items satisfy the predicate using the configuration
+pub(crate) fn split_comp_all_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig) -> bool
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    
+    // Any counterexample answers the question
+    !split_comp_any_with_config(vector, |item: &T| !predicate(item), config)
+}
+
+/// Finds in parallel the item with the lowest index satisfying the predicate.
+///
+/// Returns the index and the item of the earliest match in the input, regardless of which
+/// thread finds a match first. Every found match is published as the best index so far,
+/// and threads skip the items whose indexes aren't lower than it. `None` is returned if no
+/// item satisfies `predicate`.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_find_first;
+///
+/// let found = split_comp_find_first((1..=100).collect(), |num: &i64| num % 7 == 0);
+///
+/// assert_eq!(found, Some((6, 7)));
+/// ```
+pub fn split_comp_find_first<T, P>(vector: Vec<T>, predicate: P) -> Option<(usize, T)>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_find_first_with_config(vector, predicate, &SplitConfig::default())
+}
+
+/// Finds in parallel the item with the lowest index satisfying the predicate, the same as
+/// [`split_comp_find_first`](crate::split_comp_find_first).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_find;
+///
+/// assert_eq!(split_find((1..=1000).collect(), |num: &i64| num % 97 == 0), Some((96, 97)));
+/// ```
+pub fn split_find<T, P>(vector: Vec<T>, predicate: P) -> Option<(usize, T)>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_find_first(vector, predicate)
+}
+
+// Finding in parallel the earliest item satisfying the predicate using the configuration
+pub(crate) fn split_comp_find_first_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig)
+    -> Option<(usize, T)>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    
+    // The lowest index of a found match
+    let best = AtomicUsize::new(usize::MAX);
+    
+    let chunk_matches = run_in_chunks(vector, config, |start, chunk: Vec<T>| {
+        for (i, item) in (start..).zip(chunk) {
+            
+            // Chunk indexes only grow, so the rest of the chunk can't beat the match
+            if i >= best.load(Ordering::Relaxed) {
+                return None;
+            }
+            
+            if predicate(&item) {
+                best.fetch_min(i, Ordering::Relaxed);
+                return Some((i, item));
+            }
+        }
+        
+        None
+    });
+    
+    // Choosing the earliest of the matches of the chunks
+    chunk_matches.into_iter().flatten().min_by_key(|&(i, _)| i)
+}
+
+/// Finds in parallel the lowest index of an item satisfying the predicate.
+///
+/// Works like [`split_comp_find_first`], but returns only the index, so the result is
+/// the same as of [`Iterator::position`].
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_position;
+///
+/// assert_eq!(split_comp_position((1..=100).collect(), |num: &i64| num % 7 == 0), Some(6));
+/// ```
+pub fn split_comp_position<T, P>(vector: Vec<T>, predicate: P) -> Option<usize>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_position_with_config(vector, predicate, &SplitConfig::default())
+}
+
+// Finding in parallel the lowest index of a match using the configuration
+pub(crate) fn split_comp_position_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig)
+    -> Option<usize>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_find_first_with_config(vector, predicate, config).map(|(i, _)| i)
+}
+
+/// Finds in parallel the highest index of an item satisfying the predicate.
+///
+/// Every thread scans its chunk back to front. Every found match is published as the best
+/// index so far, and threads skip the items whose indexes aren't higher than it. The
+/// result is the same as of [`Iterator::rposition`].
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_rposition;
+///
+/// assert_eq!(split_comp_rposition((1..=100).collect(), |num: &i64| num % 7 == 0), Some(97));
+/// ```
+pub fn split_comp_rposition<T, P>(vector: Vec<T>, predicate: P) -> Option<usize>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    split_comp_rposition_with_config(vector, predicate, &SplitConfig::default())
+}
+
+// Finding in parallel the highest index of a match using the configuration
+pub(crate) fn split_comp_rposition_with_config<T, P>(vector: Vec<T>, predicate: P, config: &SplitConfig)
+    -> Option<usize>
+    where T: Send, P: Fn(&T) -> bool + Sync {
+    
+    // The highest index of a found match plus one, zero if there is none
+    let best = AtomicUsize::new(0);
+    
+    let chunk_positions = run_in_chunks(vector, config, |start, chunk: Vec<T>| {
+        let indexes = (start..start + chunk.len()).rev();
+        
+        for (i, item) in indexes.zip(chunk.into_iter().rev()) {
+            
+            // Chunk indexes only fall, so the rest of the chunk can't beat the match
+            if i < best.load(Ordering::Relaxed) {
+                return None;
+            }
+            
+            if predicate(&item) {
+                best.fetch_max(i + 1, Ordering::Relaxed);
+                return Some(i);
+            }
+        }
+        
+        None
+    });
+    
+    chunk_positions.into_iter().flatten().max()
+}
@@ -0,0 +1,129 @@
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc::Receiver, Arc};
+
+use log::{debug, trace};
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{chunk_ranges, collect_results, is_sequential, join_workers, result_channel, OutputPair, ResultSender,
+    SplitConfig, WorkerHandle, WorkerJoin};
+
+/// Splits computational work on shared data between threads.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the input isn't moved into
+/// chunks. Every thread gets a clone of the `Arc` and the range of indices it has to
+/// process, and `function` reads the items by reference. The caller keeps access to the
+/// data during and after the call.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use splitting_of_work::split_comp_work_shared;
+///
+/// let data: Arc<[i64]> = (0..100).collect();
+///
+/// let result = split_comp_work_shared(Arc::clone(&data), |num: &i64| num * 2);
+///
+/// assert_eq!(result, data.iter().map(|num| num * 2).collect::<Vec<i64>>());
+/// ```
+pub fn split_comp_work_shared<T, R, F>(data: Arc<[T]>, function: F) -> Vec<R>
+    where T: 'static + Send + Sync, R: 'static + Send,
+    F: 'static + Fn(&T) -> R + Send + Sync {
+    split_comp_work_shared_with_config(data, function, &SplitConfig::default())
+}
+
+// Splitting of computational work on shared data using the configuration
+pub(crate) fn split_comp_work_shared_with_config<T, R, F>(data: Arc<[T]>, function: F,
+    config: &SplitConfig) -> Vec<R>
+    where T: 'static + Send + Sync, R: 'static + Send,
+    F: 'static + Fn(&T) -> R + Send + Sync {
+    
+    if is_sequential(data.len(), config) {
+        
+        // Logging debugging information
+        debug!("Doing computational work in the current thread");
+        
+        return data.iter().map(function).collect();
+    }
+    
+    // Channel for transferring results of computational work
+    let (sender, receiver): (ResultSender<OutputPair<R>>, Receiver<OutputPair<R>>) = result_channel(config);
+    
+    // Function shared between threads
+    let function = Arc::new(function);
+    
+    // Handles of spawned threads
+    let mut handles: Vec<WorkerHandle> = Vec::new();
+    
+    // Whether a thread has failed to spawn, then the rest of the work is done in the
+    // current thread
+    let mut spawning_failed = false;
+    
+    // Spawning threads for computational work
+    for (i, range) in chunk_ranges(data.len(), config).into_iter().enumerate() {
+        if !spawning_failed {
+            let data_copy = Arc::clone(&data);
+            let sender_copy = sender.clone();
+            let function_copy = Arc::clone(&function);
+            
+            let range_copy = range.clone();
+            
+            let spawned = config.worker_builder(i).spawn(move || {
+                do_shared_work_in_some_thread(&data_copy, range_copy, sender_copy, &*function_copy)
+            });
+            
+            match spawned {
+                Ok(handle) => {
+                    handles.push((i, range, WorkerJoin::Thread(handle)));
+                    
+                    // Logging debugging information
+                    trace!("Thread {} has spawned", i);
+                    
+                    continue;
+                }
+                
+                // Results aren't received yet, so the work of the thread can't be done in the
+                // current thread while the bounded channel may be full. Spawned threads are
+                // detached, they stop when the receiver is dropped
+                Err(error) if matches!(sender, ResultSender::Bounded(_)) => {
+                    WorkFailure::Error(SplitError::SpawnFailed(error)).raise();
+                }
+                Err(error) => {
+                    spawning_failed = true;
+                    
+                    // Logging debugging information
+                    debug!("Thread {} has failed to spawn ({}), doing its work in the current thread", i, error);
+                }
+            }
+        }
+        
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            do_shared_work_in_some_thread(&data, range.clone(), sender.clone(), &*function)
+        }));
+        
+        handles.push((i, range, WorkerJoin::Inline(outcome)));
+    }
+    
+    // Releasing the first non-used sender
+    drop(sender);
+    
+    collect_results(receiver, join_workers(handles), data.len())
+        .unwrap_or_else(|failure| failure.raise())
+}
+
+// Doing computational work on the range of shared data in some thread
+fn do_shared_work_in_some_thread<T, R, F>(data: &[T], range: Range<usize>,
+    sender: ResultSender<OutputPair<R>>, function: &F) -> Result<(), SplitError> where F: Fn(&T) -> R {
+    let end = range.end;
+    
+    for index in range {
+        let result = (index, function(&data[index]));
+        
+        // Sending result, the rest of the range is lost if the receiving side is closed
+        sender.send(result).map_err(|_| SplitError::ResultsLost { missing: end - index })?;
+    }
+    
+    Ok(())
+}
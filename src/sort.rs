@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use crate::scoped::run_in_chunks;
+use crate::SplitConfig;
+
+/// Sorts the vector in parallel.
+///
+/// Splits the vector into chunks like [`split_comp_work`](crate::split_comp_work), every
+/// thread sorts its chunk and the sorted chunks are merged in the calling thread. Vectors
+/// shorter than [`THRESHOLD`](crate::THRESHOLD) are sorted directly. The sort is stable:
+/// chunks are sorted with [`slice::sort`], and on equal items the merge takes the item of
+/// the earlier chunk, so equal items keep their input order.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_sort;
+///
+/// assert_eq!(split_comp_sort(vec![5, 3, 9, 1, 7]), vec![1, 3, 5, 7, 9]);
+/// ```
+pub fn split_comp_sort<T>(vector: Vec<T>) -> Vec<T>
+    where T: Ord + Send {
+    split_comp_sort_with_config(vector, &SplitConfig::default())
+}
+
+// Sorting of the vector in parallel using the configuration
+pub(crate) fn split_comp_sort_with_config<T>(vector: Vec<T>, config: &SplitConfig) -> Vec<T>
+    where T: Ord + Send {
+    sort_with_compare(vector, T::cmp, config)
+}
+
+/// Sorts the vector in parallel by keys derived from the items.
+///
+/// Works like [`split_comp_sort`], but the items are compared by the keys returned by
+/// `key_fn`. The sort is stable, so items with equal keys keep their input order.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_sort_by_key;
+///
+/// let result = split_comp_sort_by_key(vec![(2, 'a'), (1, 'b'), (2, 'c')], |pair: &(i64, char)| pair.0);
+///
+/// assert_eq!(result, vec![(1, 'b'), (2, 'a'), (2, 'c')]);
+/// ```
+pub fn split_comp_sort_by_key<T, K, KF>(vector: Vec<T>, key_fn: KF) -> Vec<T>
+    where T: Send, K: Ord, KF: Fn(&T) -> K + Sync {
+    split_comp_sort_by_key_with_config(vector, key_fn, &SplitConfig::default())
+}
+
+// Sorting of the vector in parallel by keys using the configuration
+pub(crate) fn split_comp_sort_by_key_with_config<T, K, KF>(vector: Vec<T>, key_fn: KF, config: &SplitConfig)
+    -> Vec<T>
+    where T: Send, K: Ord, KF: Fn(&T) -> K + Sync {
+    sort_with_compare(vector, |a: &T, b: &T| key_fn(a).cmp(&key_fn(b)), config)
+}
+
+// Sorting of chunks in threads and merging of them in the current thread
+fn sort_with_compare<T, C>(vector: Vec<T>, compare: C, config: &SplitConfig) -> Vec<T>
+    where T: Send, C: Fn(&T, &T) -> Ordering + Sync {
+    let mut chunks = run_in_chunks(vector, config, |_, mut chunk: Vec<T>| {
+        chunk.sort_by(&compare);
+        chunk
+    });
+    
+    // Neighbouring chunks are merged pairwise until one chunk is left, so the earlier
+    // chunk is always on the left side of the merge
+    while chunks.len() > 1 {
+        let mut merged: Vec<Vec<T>> = Vec::with_capacity(chunks.len().div_ceil(2));
+        let mut chunks_iter = chunks.into_iter();
+        
+        while let Some(left) = chunks_iter.next() {
+            match chunks_iter.next() {
+                Some(right) => merged.push(merge_sorted(left, right, &compare)),
+                None => merged.push(left),
+            }
+        }
+        
+        chunks = merged;
+    }
+    
+    chunks.pop().unwrap_or_default()
+}
+
+// Merging of two sorted vectors, on equal items the item of the left vector goes first
+fn merge_sorted<T, C>(left: Vec<T>, right: Vec<T>, compare: &C) -> Vec<T>
+    where C: Fn(&T, &T) -> Ordering {
+    let mut result: Vec<T> = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    
+    while let (Some(left_item), Some(right_item)) = (left.peek(), right.peek()) {
+        let next = if compare(right_item, left_item) == Ordering::Less { right.next() } else { left.next() };
+        
+        result.extend(next);
+    }
+    
+    result.extend(left);
+    result.extend(right);
+    
+    result
+}
@@ -0,0 +1,113 @@
+use std::time::{Duration, Instant};
+
+use crate::scoped::{concat_in_order, run_in_chunks};
+use crate::{chunk_ranges, is_sequential, split_comp_work_with_config, SplitConfig};
+
+/// Statistics of how computational work has been split between threads
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkStats {
+    
+    /// Number of threads doing computational work, `1` if the work has been done in the
+    /// current thread
+    pub num_threads: usize,
+    
+    /// Number of items per thread, the last thread may get fewer items
+    pub items_per_thread: usize,
+    
+    /// Whether the work has been done in the current thread without spawning threads
+    pub sequential: bool,
+}
+
+/// Splits computational work between threads and reports how it has been split.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work) and returns the results together
+/// with [`WorkStats`]. The number of threads in the statistics is already capped by the
+/// maximum number of threads.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::SplitConfig;
+///
+/// let config = SplitConfig::builder().max_threads(8).build().unwrap();
+///
+/// let (result, stats) = config.run_with_stats((1..=34).collect(), |num: i64| num * 2);
+///
+/// assert_eq!(result.len(), 34);
+/// assert_eq!((stats.num_threads, stats.items_per_thread), (5, 7));
+/// ```
+pub fn split_comp_work_with_stats<T, R, F>(vector: Vec<T>, function: F) -> (Vec<R>, WorkStats)
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_with_stats_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with reporting of statistics using the configuration
+pub(crate) fn split_comp_work_with_stats_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> (Vec<R>, WorkStats)
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    let stats = work_stats(vector.len(), config);
+    
+    (split_comp_work_with_config(vector, function, config), stats)
+}
+
+/// Splits computational work between threads and reports how long every thread has worked.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work) and returns the results together
+/// with the time spent by every thread on its chunk, in order of the chunks. Much longer
+/// times of some chunks show that the cost of the items depends on the data, so finer
+/// chunking would balance the load better. If the work has been done in the current
+/// thread, there is one duration.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::SplitConfig;
+///
+/// let config = SplitConfig::builder().max_threads(8).build().unwrap();
+///
+/// let (result, durations) = config.run_timed((1..=34).collect(), |num: i64| num * 2);
+///
+/// assert_eq!(result.len(), 34);
+/// assert_eq!(durations.len(), 5);
+/// ```
+pub fn split_comp_work_timed<T, R, F>(vector: Vec<T>, function: F) -> (Vec<R>, Vec<Duration>)
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    split_comp_work_timed_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with timing of every thread using the configuration
+pub(crate) fn split_comp_work_timed_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> (Vec<R>, Vec<Duration>)
+    where T: Send, R: Send, F: Fn(T) -> R + Sync {
+    let len = vector.len();
+    
+    // Every thread times its own chunk and returns the time with the results
+    let timed_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        let start = Instant::now();
+        let results = chunk.into_iter().map(&function).collect::<Vec<R>>();
+        
+        (results, start.elapsed())
+    });
+    
+    let (chunk_results, durations): (Vec<Vec<R>>, Vec<Duration>) = timed_results.into_iter().unzip();
+    
+    (concat_in_order(chunk_results, len), durations)
+}
+
+// Computing statistics of splitting of the work on the given number of items, the same
+// decisions are made as by the splitting itself
+pub(crate) fn work_stats(len: usize, config: &SplitConfig) -> WorkStats {
+    if is_sequential(len, config) {
+        return WorkStats { num_threads: 1, items_per_thread: len, sequential: true };
+    }
+    
+    let ranges = chunk_ranges(len, config);
+    
+    WorkStats {
+        num_threads: ranges.len(),
+        items_per_thread: ranges.iter().map(|range| range.len()).max().unwrap_or(0),
+        sequential: false,
+    }
+}
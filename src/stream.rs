@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::sync::mpsc::Receiver;
+use std::vec;
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{first_failure, is_sequential, join_workers, spawn_workers, CancelToken, OutputPair, SplitConfig,
+    WorkerHandle};
+
+/// Iterator over results of computational work in input order.
+///
+/// Created by [`split_comp_work_stream`]. Results are yielded as soon as all results
+/// before them are ready, results which arrive early are buffered. Dropping the iterator
+/// stops threads at their next items.
+pub struct ResultStream<R> {
+    source: StreamSource<R>,
+}
+
+// Source of results of the stream
+enum StreamSource<R> {
+    
+    // Results computed in the current thread
+    Ready(vec::IntoIter<R>),
+    
+    // Results received from threads
+    Threads(ThreadResults<R>),
+}
+
+// Results received from threads together with everything needed to wait for them
+struct ThreadResults<R> {
+    receiver: Receiver<OutputPair<R>>,
+    
+    // Results which have arrived before preceding results, keyed by their indices
+    buffer: HashMap<usize, R>,
+    
+    // Index of the next result to yield
+    next_index: usize,
+    
+    len: usize,
+    workers: Vec<WorkerHandle>,
+    token: CancelToken,
+}
+
+/// Splits computational work between threads and streams the results.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but returns the results as an
+/// iterator which yields them in input order as soon as they are ready, so consuming can
+/// start before all work is done. If the vector is shorter than
+/// [`THRESHOLD`](crate::THRESHOLD), all work is done in the current thread before the
+/// iterator is returned.
+///
+/// Results which arrive before their predecessors are buffered until they can be yielded.
+/// In the worst case, when the first result arrives last, all other results are buffered
+/// at once, so the memory used is about the same as of the whole `Vec<R>`.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_stream;
+///
+/// let mut stream = split_comp_work_stream((1..=34).collect(), |num: i64| num * 2);
+///
+/// assert_eq!(stream.next(), Some(2));
+/// assert_eq!(stream.sum::<i64>(), (2..=34).map(|num| num * 2).sum());
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed by the iterator when it
+/// reaches the first result which can never arrive.
+pub fn split_comp_work_stream<T, R, F>(vector: Vec<T>, function: F) -> ResultStream<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_stream_with_config(vector, function, &SplitConfig::default())
+}
+
+/// Splits computational work between threads and streams the results in input order, the
+/// same as [`split_comp_work_stream`](crate::split_comp_work_stream).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_ordered_stream;
+///
+/// let stream = split_comp_work_ordered_stream(vec![1, 2, 3], |num: i64| num * 10);
+///
+/// assert_eq!(stream.collect::<Vec<i64>>(), vec![10, 20, 30]);
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed by the iterator when it
+/// reaches the first result which can never arrive.
+pub fn split_comp_work_ordered_stream<T, R, F>(vector: Vec<T>, function: F) -> ResultStream<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_stream(vector, function)
+}
+
+// Splitting of computational work with streaming of the results using the configuration
+pub(crate) fn split_comp_work_stream_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> ResultStream<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    if is_sequential(vector.len(), config) {
+        let result: Vec<R> = vector.into_iter().map(function).collect();
+        
+        return ResultStream { source: StreamSource::Ready(result.into_iter()) };
+    }
+    
+    let len = vector.len();
+    let token = CancelToken::new();
+    
+    let (receiver, workers) = spawn_workers(vector, function, config, Some(&token))
+        .unwrap_or_else(|failure| failure.raise());
+    
+    ResultStream {
+        source: StreamSource::Threads(ThreadResults {
+            receiver,
+            buffer: HashMap::new(),
+            next_index: 0,
+            len,
+            workers,
+            token,
+        }),
+    }
+}
+
+impl<R> Iterator for ResultStream<R> {
+    type Item = R;
+    
+    fn next(&mut self) -> Option<R> {
+        match &mut self.source {
+            StreamSource::Ready(results) => results.next(),
+            StreamSource::Threads(results) => results.next(),
+        }
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.source {
+            StreamSource::Ready(results) => results.size_hint(),
+            StreamSource::Threads(results) => {
+                let remaining = results.len - results.next_index;
+                
+                (remaining, Some(remaining))
+            }
+        }
+    }
+}
+
+impl<R> ThreadResults<R> {
+    
+    // Waiting for the next result in input order
+    fn next(&mut self) -> Option<R> {
+        if self.next_index == self.len {
+            
+            // All threads have sent their results, so they are finishing
+            join_workers(self.workers.drain(..)).for_each(drop);
+            
+            return None;
+        }
+        
+        let index = self.next_index;
+        
+        let result = match self.buffer.remove(&index) {
+            Some(result) => result,
+            None => loop {
+                match self.receiver.recv() {
+                    Ok((received_index, item)) if received_index == index => break item,
+                    Ok((received_index, item)) => {
+                        self.buffer.insert(received_index, item);
+                    }
+                    
+                    // All threads have finished, so the result will never arrive
+                    Err(_) => self.raise_failure(),
+                }
+            },
+        };
+        
+        self.next_index += 1;
+        
+        Some(result)
+    }
+    
+    // Resuming the failure of threads which has caused the loss of results
+    fn raise_failure(&mut self) -> ! {
+        let missing = self.len - self.next_index - self.buffer.len();
+        
+        first_failure(join_workers(self.workers.drain(..)))
+            .unwrap_or(WorkFailure::Error(SplitError::ResultsLost { missing }))
+            .raise()
+    }
+}
+
+impl<R> Drop for ThreadResults<R> {
+    fn drop(&mut self) {
+        
+        // Threads which are still busy stop at their next items
+        self.token.cancel();
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+
+use crate::error::{SplitError, WorkFailure};
+use crate::{first_failure, is_sequential, join_workers, spawn_workers, split_comp_work_in_threads, CancelToken,
+    OutputPair, SplitConfig};
+
+/// Results of computational work which have been received before the deadline
+#[derive(Debug, PartialEq, Eq)]
+pub struct PartialResults<R> {
+    
+    /// Results together with indices of their input items, in completion order
+    pub completed: Vec<OutputPair<R>>,
+    
+    /// Sorted indices of input items whose results haven't been received in time
+    pub missing: Vec<usize>,
+}
+
+/// Splits computational work between threads and gives up after the timeout.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but if not all results have
+/// been received within `timeout`, [`SplitError::TimedOut`] with the number of received
+/// results is returned. Items which are being processed at that moment can't be
+/// interrupted, so threads stop at their next items: finished threads are joined, and the
+/// busy ones finish their current items in the background.
+///
+/// If the work is done in the current thread, for example for vectors shorter than
+/// [`THRESHOLD`](crate::THRESHOLD), the deadline is only checked between items. Then a slow
+/// item blocks the call until it's finished, so the call can take longer than `timeout`
+/// by the time of one item.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use splitting_of_work::split_comp_work_timeout;
+///
+/// let result = split_comp_work_timeout((1..=34).collect(), |num: i64| num * 2, Duration::from_secs(5));
+///
+/// assert_eq!(result.unwrap(), (1..=34).map(|num| num * 2).collect::<Vec<i64>>());
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_work_timeout<T, R, F>(vector: Vec<T>, function: F, timeout: Duration)
+    -> Result<Vec<R>, SplitError>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_timeout_with_config(vector, function, timeout, &SplitConfig::default())
+}
+
+// Splitting of computational work with the timeout using the configuration
+pub(crate) fn split_comp_work_timeout_with_config<T, R, F>(vector: Vec<T>, function: F,
+    timeout: Duration, config: &SplitConfig) -> Result<Vec<R>, SplitError>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    let deadline = Instant::now() + timeout;
+    
+    // Token for stopping threads once the deadline has passed
+    let token = CancelToken::new();
+    
+    // Only the timeout is reported as an error, other failures are raised
+    split_comp_work_in_threads(vector, function, config, &|_, _| {}, Some(&token), Some(deadline))
+        .map_err(|failure| match failure {
+            WorkFailure::Error(error @ SplitError::TimedOut { .. }) => error,
+            failure => failure.raise(),
+        })
+}
+
+/// Splits computational work between threads and keeps the results received before the
+/// deadline.
+///
+/// Works like [`split_comp_work_timeout`], but instead of an error the results received
+/// within `timeout` are returned together with indices of the items whose results are
+/// missing. Threads which are busy at the deadline are detached: their current items may
+/// still run to completion, but their results are discarded and no further items are
+/// started.
+///
+/// If the work is done in the current thread, for example for vectors shorter than
+/// [`THRESHOLD`](crate::THRESHOLD), the deadline is only checked between items. Then a slow
+/// item blocks the call until it's finished and its result is kept, so the call can take
+/// longer than `timeout` by the time of one item.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use splitting_of_work::split_comp_work_timeout_partial;
+///
+/// let result = split_comp_work_timeout_partial((1..=34).collect(), |num: i64| num * 2,
+///     Duration::from_secs(5));
+///
+/// assert_eq!(result.completed.len(), 34);
+/// assert!(result.missing.is_empty());
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread before the deadline, the panic is resumed in the
+/// calling thread after all threads have finished.
+pub fn split_comp_work_timeout_partial<T, R, F>(vector: Vec<T>, function: F, timeout: Duration)
+    -> PartialResults<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_timeout_partial_with_config(vector, function, timeout, &SplitConfig::default())
+}
+
+// Splitting of computational work with keeping the results received before the deadline
+// using the configuration
+pub(crate) fn split_comp_work_timeout_partial_with_config<T, R, F>(vector: Vec<T>, function: F,
+    timeout: Duration, config: &SplitConfig) -> PartialResults<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    let deadline = Instant::now() + timeout;
+    let len = vector.len();
+    
+    let mut completed: Vec<OutputPair<R>> = Vec::with_capacity(len);
+    
+    if is_sequential(len, config) {
+        for (index, item) in vector.into_iter().enumerate() {
+            if Instant::now() >= deadline {
+                break;
+            }
+            
+            completed.push((index, function(item)));
+        }
+        
+        return PartialResults { missing: (completed.len()..len).collect(), completed };
+    }
+    
+    // Token for stopping threads once the deadline has passed
+    let token = CancelToken::new();
+    
+    let (receiver, workers) = spawn_workers(vector, function, config, Some(&token))
+        .unwrap_or_else(|failure| failure.raise());
+    
+    // Receiving results until all threads have finished or the deadline has passed
+    loop {
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(received) => completed.push(received),
+            Err(RecvTimeoutError::Disconnected) => {
+                if let Some(failure) = first_failure(join_workers(workers)) {
+                    failure.raise();
+                }
+                
+                break;
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                token.cancel();
+                
+                // Finished threads are joined and busy ones are detached
+                workers.into_iter()
+                    .filter(|(_, _, handle)| handle.is_finished())
+                    .for_each(|(_, _, handle)| drop(handle.join()));
+                
+                break;
+            }
+        }
+    }
+    
+    // Indices without results
+    let mut is_completed: Vec<bool> = vec![false; len];
+    
+    for (index, _) in &completed {
+        is_completed[*index] = true;
+    }
+    
+    let missing: Vec<usize> = (0..len).filter(|index| !is_completed[*index]).collect();
+    
+    PartialResults { completed, missing }
+}
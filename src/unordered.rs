@@ -0,0 +1,183 @@
+use std::sync::mpsc::Receiver;
+use std::vec;
+
+use crate::{do_comp_work_in_cur_thread, first_failure, is_sequential, join_workers, spawn_workers, CancelToken,
+    OutputPair, SplitConfig, WorkerHandle};
+
+/// Splits computational work between threads and returns the results in completion order.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the results aren't
+/// reassembled into input order. Every result comes with the index of its input item, and
+/// the pairs are returned in the order they have been received from threads, which is
+/// nondeterministic. If the work is done in the current thread, the pairs are in input
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_unordered;
+///
+/// let mut result = split_comp_work_unordered((1..=34).collect(), |num: i64| num * 2);
+///
+/// result.sort_by_key(|(index, _)| *index);
+///
+/// assert_eq!(result[0], (0, 2));
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed in the calling thread after
+/// all threads have finished.
+pub fn split_comp_work_unordered<T, R, F>(vector: Vec<T>, function: F) -> Vec<OutputPair<R>>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_unordered_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with results in completion order using the configuration
+pub(crate) fn split_comp_work_unordered_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> Vec<OutputPair<R>>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    if is_sequential(vector.len(), config) {
+        return do_comp_work_in_cur_thread(vector.into_iter().enumerate().collect(), |(index, item)| {
+            (index, function(item))
+        });
+    }
+    
+    let (receiver, workers) = spawn_workers(vector, function, config, None)
+        .unwrap_or_else(|failure| failure.raise());
+    
+    // Results are kept in the order they are received
+    let result: Vec<OutputPair<R>> = receiver.into_iter().collect();
+    
+    if let Some(failure) = first_failure(join_workers(workers)) {
+        failure.raise();
+    }
+    
+    result
+}
+
+/// Iterator over results of computational work in completion order.
+///
+/// Created by [`split_comp_work_unordered_iter`]. Every result comes with the index of its
+/// input item, which can be used for reordering. Dropping the iterator stops threads at
+/// their next items.
+pub struct UnorderedResults<R> {
+    source: UnorderedSource<R>,
+}
+
+// Source of results of the iterator
+enum UnorderedSource<R> {
+    
+    // Results computed in the current thread
+    Ready(vec::IntoIter<OutputPair<R>>),
+    
+    // Results received from threads
+    Threads {
+        receiver: Receiver<OutputPair<R>>,
+        remaining: usize,
+        workers: Vec<WorkerHandle>,
+        token: CancelToken,
+    },
+}
+
+/// Splits computational work between threads and returns the results lazily in completion
+/// order.
+///
+/// Works like [`split_comp_work_unordered`], but returns an iterator which yields every
+/// result as soon as a thread has sent it, so consuming can start before all work is done.
+/// The order of the pairs is nondeterministic, the index of the input item is provided for
+/// reordering if needed. Threads are kept alive until the iterator is exhausted. If the
+/// vector is shorter than [`THRESHOLD`](crate::THRESHOLD), all work is done in the current
+/// thread before the iterator is returned.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_work_unordered_iter;
+///
+/// let mut result: Vec<_> = split_comp_work_unordered_iter((1..=34).collect(), |num: i64| num * 2)
+///     .collect();
+///
+/// result.sort_by_key(|(index, _)| *index);
+///
+/// assert_eq!(result[33], (33, 68));
+/// ```
+///
+/// # Panics
+///
+/// If `function` panics in any thread, the panic is resumed by the iterator after the
+/// results of all other threads have been yielded.
+pub fn split_comp_work_unordered_iter<T, R, F>(vector: Vec<T>, function: F) -> UnorderedResults<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    split_comp_work_unordered_iter_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work with lazy results in completion order using the configuration
+pub(crate) fn split_comp_work_unordered_iter_with_config<T, R, F>(vector: Vec<T>, function: F,
+    config: &SplitConfig) -> UnorderedResults<R>
+    where T: 'static + Send, R: 'static + Send,
+    F: 'static + Fn(T) -> R + Send + Sync {
+    
+    if is_sequential(vector.len(), config) {
+        let result: Vec<OutputPair<R>> = vector.into_iter().map(function).enumerate().collect();
+        
+        return UnorderedResults { source: UnorderedSource::Ready(result.into_iter()) };
+    }
+    
+    let remaining = vector.len();
+    let token = CancelToken::new();
+    
+    let (receiver, workers) = spawn_workers(vector, function, config, Some(&token))
+        .unwrap_or_else(|failure| failure.raise());
+    
+    UnorderedResults { source: UnorderedSource::Threads { receiver, remaining, workers, token } }
+}
+
+impl<R> Iterator for UnorderedResults<R> {
+    type Item = OutputPair<R>;
+    
+    fn next(&mut self) -> Option<OutputPair<R>> {
+        match &mut self.source {
+            UnorderedSource::Ready(results) => results.next(),
+            UnorderedSource::Threads { receiver, remaining, workers, .. } => match receiver.recv() {
+                Ok(pair) => {
+                    *remaining -= 1;
+                    
+                    Some(pair)
+                }
+                
+                // All threads have finished, so their failure is resumed if there is one
+                Err(_) => {
+                    if let Some(failure) = first_failure(join_workers(workers.drain(..))) {
+                        failure.raise();
+                    }
+                    
+                    None
+                }
+            },
+        }
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.source {
+            UnorderedSource::Ready(results) => results.size_hint(),
+            
+            // Results of panicked threads never arrive
+            UnorderedSource::Threads { remaining, .. } => (0, Some(*remaining)),
+        }
+    }
+}
+
+impl<R> Drop for UnorderedResults<R> {
+    fn drop(&mut self) {
+        
+        // Threads which are still busy stop at their next items
+        if let UnorderedSource::Threads { token, .. } = &self.source {
+            token.cancel();
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::error::SplitError;
+use crate::scoped::{concat_in_order, run_in_chunks, split_comp_work_scoped_with_config};
+use crate::SplitConfig;
+
+/// Splits computational work on two vectors between threads.
+///
+/// Applies `function` to the pairs of items with equal indices and returns the results in
+/// input order. Both vectors are split into chunks in lockstep like in
+/// [`split_comp_work`](crate::split_comp_work). If the lengths of the vectors differ,
+/// [`SplitError::LengthMismatch`] is returned and no work is done.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_zip_map;
+///
+/// let result = split_zip_map(vec![1, 2, 3], vec![10, 20, 30], |a: i64, b: i64| a + b);
+///
+/// assert_eq!(result.unwrap(), vec![11, 22, 33]);
+/// ```
+pub fn split_zip_map<A, B, R, F>(a: Vec<A>, b: Vec<B>, function: F) -> Result<Vec<R>, SplitError>
+    where A: Send, B: Send, R: Send, F: Fn(A, B) -> R + Sync {
+    split_zip_map_with_config(a, b, function, &SplitConfig::default())
+}
+
+/// Splits computational work on two vectors between threads, the same as
+/// [`split_zip_map`](crate::split_zip_map).
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_zip_map;
+///
+/// let result = split_comp_zip_map(vec![1, 2], vec![3, 4], |a: i64, b: i64| a * b);
+///
+/// assert_eq!(result.unwrap(), vec![3, 8]);
+/// ```
+pub fn split_comp_zip_map<A, B, R, F>(a: Vec<A>, b: Vec<B>, function: F) -> Result<Vec<R>, SplitError>
+    where A: Send, B: Send, R: Send, F: Fn(A, B) -> R + Sync {
+    split_zip_map(a, b, function)
+}
+
+// Splitting of computational work on two vectors using the configuration
+pub(crate) fn split_zip_map_with_config<A, B, R, F>(a: Vec<A>, b: Vec<B>, function: F,
+    config: &SplitConfig) -> Result<Vec<R>, SplitError>
+    where A: Send, B: Send, R: Send, F: Fn(A, B) -> R + Sync {
+    if a.len() != b.len() {
+        return Err(SplitError::LengthMismatch { left: a.len(), right: b.len() });
+    }
+    
+    let pairs: Vec<(A, B)> = a.into_iter().zip(b).collect();
+    
+    Ok(split_comp_work_scoped_with_config(pairs, |(a, b)| function(a, b), config))
+}
+
+/// Splits computational work returning pairs between threads and unzips the results.
+///
+/// Works like [`split_comp_work`](crate::split_comp_work), but the pairs returned by
+/// `function` are split into two vectors in input order, so no second pass over the
+/// results is needed. Every chunk is unzipped locally, and the parts of the chunks are
+/// joined in chunk order.
+///
+/// # Examples
+///
+/// ```
+/// use splitting_of_work::split_comp_unzip;
+///
+/// let (nums, squares) = split_comp_unzip(vec![1, 2, 3], |num: i64| (num, num * num));
+///
+/// assert_eq!(nums, vec![1, 2, 3]);
+/// assert_eq!(squares, vec![1, 4, 9]);
+/// ```
+pub fn split_comp_unzip<T, A, B, F>(vector: Vec<T>, function: F) -> (Vec<A>, Vec<B>)
+    where T: Send, A: Send, B: Send, F: Fn(T) -> (A, B) + Sync {
+    split_comp_unzip_with_config(vector, function, &SplitConfig::default())
+}
+
+// Splitting of computational work returning pairs with unzipping using the configuration
+pub(crate) fn split_comp_unzip_with_config<T, A, B, F>(vector: Vec<T>, function: F, config: &SplitConfig)
+    -> (Vec<A>, Vec<B>)
+    where T: Send, A: Send, B: Send, F: Fn(T) -> (A, B) + Sync {
+    let len = vector.len();
+    
+    let chunk_results = run_in_chunks(vector, config, |_, chunk: Vec<T>| {
+        chunk.into_iter().map(&function).unzip::<A, B, Vec<A>, Vec<B>>()
+    });
+    
+    let (a, b): (Vec<Vec<A>>, Vec<Vec<B>>) = chunk_results.into_iter().unzip();
+    
+    (concat_in_order(a, len), concat_in_order(b, len))
+}
@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+use splitting_of_work::{split_comp_work_adaptive, SplitConfig};
+
+// Configuration allowing several threads on any hardware
+fn config() -> SplitConfig {
+    SplitConfig::builder().max_threads(4).build().unwrap()
+}
+
+// Number of distinct threads which have processed the items
+fn num_of_threads(thread_ids: &[ThreadId]) -> usize {
+    thread_ids.iter().collect::<HashSet<_>>().len()
+}
+
+#[test]
+fn keeps_order() {
+    let result = split_comp_work_adaptive((0..1000).collect(), |num: u64| num * 3, Duration::from_micros(100));
+    
+    assert_eq!(result, (0..1000).map(|num| num * 3).collect::<Vec<u64>>());
+}
+
+#[test]
+fn trivial_function_stays_in_current_thread() {
+    let current = thread::current().id();
+    
+    let thread_ids = config().run_adaptive((0..1000).collect(), |_: u32| thread::current().id(),
+        Duration::from_millis(100));
+    
+    assert_eq!(thread_ids.len(), 1000);
+    assert!(thread_ids.iter().all(|id| *id == current));
+}
+
+#[test]
+fn sleepy_function_fans_out() {
+    let thread_ids = config().run_adaptive((0..20).collect(), |_: u32| {
+        thread::sleep(Duration::from_millis(2));
+        thread::current().id()
+    }, Duration::from_micros(100));
+    
+    assert_eq!(thread_ids.len(), 20);
+    assert!(num_of_threads(&thread_ids) > 1);
+}
+
+#[test]
+fn short_vector_is_only_sampled() {
+    let current = thread::current().id();
+    
+    let thread_ids = config().run_adaptive(vec![1, 2, 3], |_: u32| {
+        thread::sleep(Duration::from_millis(1));
+        thread::current().id()
+    }, Duration::from_nanos(1));
+    
+    assert!(thread_ids.iter().all(|id| *id == current));
+}
+
+#[test]
+fn empty_vector() {
+    let result = split_comp_work_adaptive(Vec::<i32>::new(), |num: i32| num, Duration::from_micros(100));
+    
+    assert!(result.is_empty());
+}
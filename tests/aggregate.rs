@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+
+use splitting_of_work::{split_comp_count, split_comp_max, split_comp_min, split_comp_sum, SplitConfig};
+
+// Example of client's item which is compared only by its key
+#[derive(Debug)]
+struct Keyed {
+    key: i64,
+    position: usize,
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+// Creates items whose keys repeat, so minimums and maximums have ties
+fn keyed_items(len: usize) -> Vec<Keyed> {
+    (0..len).map(|position| Keyed { key: (position % 10) as i64, position }).collect()
+}
+
+#[test]
+fn sum_matches_sequential() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert_eq!(config.run_sum((1..=1000).collect::<Vec<i64>>()), (1..=1000).sum::<i64>());
+    assert_eq!(split_comp_sum(Vec::<i64>::new()), 0);
+}
+
+#[test]
+fn min_and_max_return_first_occurrence() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let min = config.run_min(keyed_items(100)).unwrap();
+    let max = config.run_max(keyed_items(100)).unwrap();
+    
+    assert_eq!((min.key, min.position), (0, 0));
+    assert_eq!((max.key, max.position), (9, 9));
+}
+
+#[test]
+fn min_and_max_of_small_vectors() {
+    assert_eq!(split_comp_min(Vec::<i64>::new()), None);
+    assert_eq!(split_comp_max(Vec::<i64>::new()), None);
+    assert_eq!(split_comp_min(vec![5]), Some(5));
+    assert_eq!(split_comp_max(vec![5]), Some(5));
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+#[cfg(debug_assertions)]
+fn sum_overflow_panics() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    config.run_sum(vec![u8::MAX; 100]);
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+#[cfg(debug_assertions)]
+fn sum_overflow_depends_on_split() {
+    let config = SplitConfig::builder().threshold(2).max_threads(2).build().unwrap();
+    let vector: Vec<i64> = vec![-1, 0, i64::MAX, 1];
+    
+    // Every prefix sum of the left fold fits into `i64`
+    assert_eq!(vector.iter().sum::<i64>(), i64::MAX);
+    
+    // Split as `[-1, 0]` and `[i64::MAX, 1]`, the second partial sum overflows
+    config.run_sum(vector);
+}
+
+#[test]
+fn wider_type_avoids_overflow_of_partial_sums() {
+    let config = SplitConfig::builder().threshold(2).max_threads(2).build().unwrap();
+    
+    // Split as `[-1, 0]` and `[i64::MAX, 1]`, the second partial sum doesn't fit into `i64`
+    // while every prefix sum of the left fold does
+    let vector: Vec<i128> = [-1, 0, i64::MAX, 1].into_iter().map(i128::from).collect();
+    
+    assert_eq!(config.run_sum(vector), i128::from(i64::MAX));
+}
+
+#[test]
+fn count_matches_iterator() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..1000).map(|num| (num * 37) % 101).collect();
+    
+    let expected = vector.iter().filter(|num| **num > 50).count();
+    
+    assert_eq!(config.run_count(vector, |num: &i64| *num > 50), expected);
+}
+
+#[test]
+fn count_below_threshold_and_empty() {
+    assert_eq!(split_comp_count(vec![1, 2, 3], |num: &i64| *num != 2), 2);
+    assert_eq!(split_comp_count(Vec::<i64>::new(), |_| true), 0);
+}
@@ -0,0 +1,114 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use splitting_of_work::{split_comp_work_cancellable, CancelToken, SplitConfig, SplitError};
+
+#[test]
+fn not_cancelled_work_returns_results() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let token = CancelToken::new();
+    
+    let result = config.run_cancellable((0..100).collect(), |num: i64| num * 2, &token);
+    
+    assert_eq!(result.unwrap(), (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+fn cancelled_before_call_does_no_work() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let token = CancelToken::new();
+    let num_of_calls = Arc::new(AtomicUsize::new(0));
+    let num_of_calls_copy = Arc::clone(&num_of_calls);
+    
+    token.cancel();
+    
+    let result = config.run_cancellable((0..100).collect(), move |num: i64| {
+        num_of_calls_copy.fetch_add(1, Ordering::Relaxed);
+        num
+    }, &token);
+    
+    assert!(matches!(result, Err(SplitError::Cancelled)));
+    assert_eq!(num_of_calls.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn cancelled_from_another_thread() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let token = CancelToken::new();
+    let num_of_calls = Arc::new(AtomicUsize::new(0));
+    let num_of_calls_copy = Arc::clone(&num_of_calls);
+    
+    // Cancelling after the first few items are done
+    let canceller = {
+        let token = token.clone();
+        let num_of_calls = Arc::clone(&num_of_calls);
+        
+        thread::spawn(move || {
+            while num_of_calls.load(Ordering::Relaxed) < 4 {
+                thread::yield_now();
+            }
+            
+            token.cancel();
+        })
+    };
+    
+    let result = config.run_cancellable((0..1000).collect(), move |num: i64| {
+        num_of_calls_copy.fetch_add(1, Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(1));
+        num
+    }, &token);
+    
+    canceller.join().unwrap();
+    
+    assert!(matches!(result, Err(SplitError::Cancelled)));
+    assert!(num_of_calls.load(Ordering::Relaxed) < 1000);
+}
+
+#[test]
+fn cancelled_on_sequential_path() {
+    let token = CancelToken::new();
+    let token_copy = token.clone();
+    
+    let result = split_comp_work_cancellable(vec![1, 2, 3], move |num: i64| {
+        token_copy.cancel();
+        num
+    }, &token);
+    
+    assert!(matches!(result, Err(SplitError::Cancelled)));
+}
+
+#[test]
+fn cancelling_finished_work_is_no_op() {
+    let token = CancelToken::new();
+    
+    let result = split_comp_work_cancellable((0..34).collect(), |num: i64| num + 1, &token);
+    
+    token.cancel();
+    
+    assert_eq!(result.unwrap(), (1..=34).collect::<Vec<i64>>());
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn stop_flag_set_mid_run_cancels() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let stop_flag_copy = Arc::clone(&stop_flag);
+    let num_of_calls = Arc::new(AtomicUsize::new(0));
+    let num_of_calls_copy = Arc::clone(&num_of_calls);
+    
+    let result = config.run_cancellable((0..1000).collect(), move |num: i64| {
+        if num_of_calls_copy.fetch_add(1, Ordering::Relaxed) == 8 {
+            stop_flag_copy.store(true, Ordering::Relaxed);
+        }
+        
+        thread::sleep(Duration::from_millis(1));
+        num
+    }, &CancelToken::from(Arc::clone(&stop_flag)));
+    
+    assert!(matches!(result, Err(SplitError::Cancelled)));
+    assert!(stop_flag.load(Ordering::Relaxed));
+    assert!(num_of_calls.load(Ordering::Relaxed) < 1000);
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use splitting_of_work::{split_comp_work_channel, SplitConfig};
+
+#[test]
+fn items_from_producer_are_processed() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let (sender, receiver) = mpsc::channel();
+    
+    let results = config.run_channel(receiver, |num: i64| num * 2);
+    
+    // Producing items over time in another thread
+    let producer = thread::spawn(move || {
+        for num in 0..100 {
+            sender.send(num).unwrap();
+            
+            if num % 10 == 0 {
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+    });
+    
+    let mut result: Vec<(usize, i64)> = results.iter().collect();
+    producer.join().unwrap();
+    
+    result.sort_by_key(|(index, _)| *index);
+    
+    assert_eq!(result, (0..100).map(|num| (num as usize, num * 2)).collect::<Vec<(usize, i64)>>());
+}
+
+#[test]
+fn work_is_shared_between_threads() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let thread_ids = Arc::new(Mutex::new(HashSet::new()));
+    let thread_ids_copy = Arc::clone(&thread_ids);
+    let (sender, receiver) = mpsc::channel();
+    
+    let results = config.run_channel(receiver, move |num: i64| {
+        thread_ids_copy.lock().unwrap().insert(thread::current().id());
+        thread::sleep(Duration::from_millis(5));
+        num
+    });
+    
+    for num in 0..20 {
+        sender.send(num).unwrap();
+    }
+    
+    drop(sender);
+    
+    assert_eq!(results.iter().count(), 20);
+    
+    let thread_ids = thread_ids.lock().unwrap();
+    
+    assert!(thread_ids.len() > 1 && thread_ids.len() <= 4);
+    assert!(!thread_ids.contains(&thread::current().id()));
+}
+
+#[test]
+fn output_closes_when_producer_is_dropped() {
+    let (sender, receiver) = mpsc::channel::<i64>();
+    
+    let results = split_comp_work_channel(receiver, |num: i64| num);
+    
+    drop(sender);
+    
+    assert!(results.recv().is_err());
+}
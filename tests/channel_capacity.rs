@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use splitting_of_work::SplitConfig;
+
+// Size of one large result in bytes
+const RESULT_SIZE: usize = 1 << 20;
+
+#[test]
+fn channel_is_unbounded_by_default() {
+    assert_eq!(SplitConfig::default().channel_capacity(), None);
+    assert_eq!(SplitConfig::builder().channel_capacity(4).build().unwrap().channel_capacity(), Some(4));
+}
+
+#[test]
+fn bounded_channel_limits_buffered_results() {
+    let config = SplitConfig::builder().max_threads(4).channel_capacity(2).build().unwrap();
+    let num_of_computed = Arc::new(AtomicUsize::new(0));
+    let num_of_computed_copy = Arc::clone(&num_of_computed);
+    
+    let mut stream = config.run_stream((0..100).collect(), move |num: usize| {
+        num_of_computed_copy.fetch_add(1, Ordering::Relaxed);
+        vec![num as u8; RESULT_SIZE]
+    });
+    
+    // Nothing is received, so every thread waits with at most one result
+    thread::sleep(Duration::from_millis(50));
+    
+    assert!(num_of_computed.load(Ordering::Relaxed) <= 2 + 4);
+    
+    assert_eq!(stream.next().unwrap(), vec![0; RESULT_SIZE]);
+    assert_eq!(stream.count(), 99);
+}
+
+#[test]
+fn bounded_channel_returns_all_results() {
+    for capacity in [0, 1, 16] {
+        let config = SplitConfig::builder().max_threads(4).channel_capacity(capacity).build().unwrap();
+        
+        let result = config.run(0..100, |num: usize| vec![num as u8; 1024]);
+        let progress_result = config.run_with_progress((0..100).collect(), |num: usize| num, |_, _| {});
+        
+        assert!(result.iter().enumerate().all(|(num, item)| *item == vec![num as u8; 1024]));
+        assert_eq!(progress_result, (0..100).collect::<Vec<usize>>());
+    }
+}
+
+#[test]
+fn shared_work_returns_all_results_through_bounded_channel() {
+    let data: Arc<[usize]> = (0..1000).collect();
+    
+    for capacity in [0, 1, 16] {
+        let config = SplitConfig::builder().max_threads(4).channel_capacity(capacity).build().unwrap();
+        
+        assert_eq!(config.run_shared(Arc::clone(&data), |num: &usize| num * 2),
+            (0..1000).map(|num| num * 2).collect::<Vec<usize>>());
+    }
+}
+
+#[test]
+fn bounded_output_of_channel_limits_buffered_results() {
+    let config = SplitConfig::builder().max_threads(4).channel_capacity(2).build().unwrap();
+    let num_of_computed = Arc::new(AtomicUsize::new(0));
+    let num_of_computed_copy = Arc::clone(&num_of_computed);
+    
+    let (sender, receiver) = mpsc::channel();
+    
+    for num in 0..100 {
+        sender.send(num).unwrap();
+    }
+    
+    drop(sender);
+    
+    let output = config.run_channel(receiver, move |num: usize| {
+        num_of_computed_copy.fetch_add(1, Ordering::Relaxed);
+        num
+    });
+    
+    // Nothing is received, so every thread waits with at most one result
+    thread::sleep(Duration::from_millis(50));
+    
+    assert!(num_of_computed.load(Ordering::Relaxed) <= 2 + 4);
+    assert_eq!(output.iter().count(), 100);
+}
@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use std::sync::Mutex;
+
+use splitting_of_work::{partition, split_comp_map_chunks, split_comp_work_chunked, SplitConfig, THRESHOLD};
+
+#[test]
+fn function_is_called_once_per_chunk() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_calls = AtomicUsize::new(0);
+    
+    let result = config.run_chunked((0..100).collect(), |chunk: &[i64]| {
+        num_of_calls.fetch_add(1, Ordering::Relaxed);
+        chunk.iter().map(|num| num * num).collect()
+    });
+    
+    assert_eq!(result, (0..100).map(|num| num * num).collect::<Vec<i64>>());
+    assert_eq!(num_of_calls.into_inner(), 4);
+}
+
+#[test]
+fn chunked_on_sequential_path() {
+    let result = split_comp_work_chunked(vec![1.0, 2.0, 4.0], |chunk: &[f64]| {
+        let sum: f64 = chunk.iter().sum();
+        
+        chunk.iter().map(|value| value / sum).collect()
+    });
+    
+    assert_eq!(result, vec![1.0 / 7.0, 2.0 / 7.0, 4.0 / 7.0]);
+}
+
+#[test]
+#[should_panic(expected = "Function returned 24 results for the chunk of 25 items starting at index 25")]
+fn wrong_number_of_results_is_rejected() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    config.run_chunked((0..100).collect(), |chunk: &[i64]| {
+        if chunk[0] == 25 {
+            chunk[1..].to_vec()
+        } else {
+            chunk.to_vec()
+        }
+    });
+}
+
+#[test]
+fn chunks_follow_partition() {
+    let config = SplitConfig::builder().max_threads(3).build().unwrap();
+    let chunk_ranges = Mutex::new(Vec::new());
+    
+    config.run_chunked((0..50).collect(), |chunk: &[usize]| {
+        chunk_ranges.lock().unwrap().push(chunk[0]..chunk[0] + chunk.len());
+        chunk.to_vec()
+    });
+    
+    let mut chunk_ranges = chunk_ranges.into_inner().unwrap();
+    chunk_ranges.sort_by_key(|range| range.start);
+    
+    assert_eq!(chunk_ranges, partition(50, THRESHOLD, 3));
+}
+
+#[test]
+fn sequential_path_calls_function_once_on_whole_input() {
+    let config = SplitConfig::builder().max_threads(1).build().unwrap();
+    let chunk_lengths = Mutex::new(Vec::new());
+    
+    let result = config.run_chunked((0..50).collect(), |chunk: &[i64]| {
+        chunk_lengths.lock().unwrap().push(chunk.len());
+        chunk.to_vec()
+    });
+    
+    assert_eq!(result.len(), 50);
+    assert_eq!(chunk_lengths.into_inner().unwrap(), vec![50]);
+}
+
+#[test]
+fn split_comp_map_chunks_keeps_order() {
+    let result = split_comp_map_chunks((0..1000).collect(), |chunk: &[u64]| {
+        chunk.iter().map(|num| num * 3).collect()
+    });
+    
+    assert_eq!(result, (0..1000).map(|num| num * 3).collect::<Vec<u64>>());
+}
@@ -0,0 +1,94 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use splitting_of_work::{split_comp_collect, split_comp_extend, split_comp_work, SplitConfig, THRESHOLD};
+
+// Configuration allowing several threads on any hardware
+fn config() -> SplitConfig {
+    SplitConfig::builder().threshold(1).max_threads(4).build().unwrap()
+}
+
+#[test]
+fn collects_into_string() {
+    let text = "splitting of computational work";
+    
+    let result: String = split_comp_collect(text.chars().collect(), |c: char| c.to_ascii_uppercase());
+    
+    assert_eq!(result, text.to_ascii_uppercase());
+}
+
+#[test]
+fn collects_into_string_in_threads() {
+    let text = "splitting of computational work between threads";
+    
+    let result: String = config().run_collect(text.chars().collect(), |c: char| c.to_ascii_uppercase());
+    
+    assert_eq!(result, text.to_ascii_uppercase());
+}
+
+#[test]
+fn collects_into_hash_map_keyed_by_index() {
+    let vector: Vec<(usize, i64)> = (0..100).map(|num| (num as usize, num)).collect();
+    
+    let result: HashMap<usize, i64> = config().run_collect(vector, |(index, num)| (index, num * num));
+    
+    assert_eq!(result.len(), 100);
+    assert!(result.iter().all(|(index, square)| (*index * *index) as i64 == *square));
+}
+
+#[test]
+fn later_keys_win_like_sequential_collect() {
+    let vector: Vec<i64> = (0..50).collect();
+    
+    let result: BTreeMap<i64, i64> = config().run_collect(vector.clone(), |num| (num % 5, num));
+    let expected: BTreeMap<i64, i64> = vector.into_iter().map(|num| (num % 5, num)).collect();
+    
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn collects_into_hash_set() {
+    let result: HashSet<i64> = split_comp_collect((0..100).collect(), |num: i64| num % 7);
+    
+    assert_eq!(result, (0..7).collect::<HashSet<i64>>());
+}
+
+#[test]
+fn collects_into_vec_like_split_comp_work() {
+    let vector: Vec<i64> = (0..1000).collect();
+    
+    let result: Vec<i64> = config().run_collect(vector.clone(), |num| num * 3);
+    
+    assert_eq!(result, split_comp_work(vector, |num: i64| num * 3));
+}
+
+#[test]
+fn batches_are_appended_in_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let mut out: Vec<i64> = vec![-1, -2];
+    
+    // Batches below, at and above the threshold
+    let batches: Vec<Vec<i64>> = vec![
+        (0..THRESHOLD as i64 - 1).collect(),
+        (100..100 + THRESHOLD as i64).collect(),
+        (200..300).collect(),
+    ];
+    
+    for batch in batches.clone() {
+        config.run_extend(&mut out, batch, |num| num * 2);
+    }
+    
+    let mut expected: Vec<i64> = vec![-1, -2];
+    expected.extend(batches.into_iter().flatten().map(|num| num * 2));
+    
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn extend_reserves_capacity_up_front() {
+    let mut out: Vec<i64> = Vec::new();
+    
+    split_comp_extend(&mut out, (0..1000).collect(), |num: i64| num + 1);
+    
+    assert_eq!(out, (1..1001).collect::<Vec<i64>>());
+    assert!(out.capacity() >= 1000);
+}
@@ -0,0 +1,127 @@
+use std::thread;
+
+use splitting_of_work::{default_max_threads, split_comp_work_with_config, ConfigError, SplitConfig, WorkConfig, THRESHOLD};
+
+#[test]
+fn default_config() {
+    let config = SplitConfig::default();
+    
+    assert_eq!(config.threshold(), THRESHOLD);
+    assert_eq!(config.max_threads(), default_max_threads());
+}
+
+#[test]
+fn builder_sets_values() {
+    let config = SplitConfig::builder().threshold(16).max_threads(8).build().unwrap();
+    
+    assert_eq!(config.threshold(), 16);
+    assert_eq!(config.max_threads(), 8);
+}
+
+#[test]
+fn zero_threshold_is_rejected() {
+    let result = SplitConfig::builder().threshold(0).build();
+    
+    assert_eq!(result, Err(ConfigError::ZeroThreshold));
+}
+
+#[test]
+fn zero_max_threads_is_rejected() {
+    let result = SplitConfig::builder().max_threads(0).build();
+    
+    assert_eq!(result, Err(ConfigError::ZeroMaxThreads));
+}
+
+#[test]
+fn config_is_reusable() {
+    let config = SplitConfig::builder().threshold(4).max_threads(2).build().unwrap();
+    let config_copy = config.clone();
+    
+    let result = config.run(0..50, |num: i64| num * num);
+    let result_copy = config_copy.run(0..3, |num: i64| num * num);
+    
+    assert_eq!(result, (0..50).map(|num| num * num).collect::<Vec<i64>>());
+    assert_eq!(result_copy, vec![0, 1, 4]);
+}
+
+#[test]
+fn worker_threads_are_named() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..100).collect();
+    
+    let mut names: Vec<String> = config.run_ref(&vector, |_: &i64| thread::current().name().unwrap().to_string());
+    
+    names.dedup();
+    
+    assert_eq!(names, vec!["split-worker-0", "split-worker-1", "split-worker-2", "split-worker-3"]);
+}
+
+#[test]
+fn stack_size_is_configurable() {
+    assert_eq!(SplitConfig::default().stack_size(), None);
+    
+    let config = SplitConfig::builder().threshold(1).max_threads(4).stack_size(64 * 1024 * 1024).build().unwrap();
+    
+    assert_eq!(config.stack_size(), Some(64 * 1024 * 1024));
+    
+    // A deep recursion which needs more than a minimal stack
+    fn depth(num: u64) -> u64 {
+        let padding = [num; 64];
+        
+        if num == 0 { 0 } else { 1 + depth(padding[63] - 1) }
+    }
+    
+    assert_eq!(config.run(vec![10_000; 4], depth), vec![10_000; 4]);
+}
+
+#[test]
+fn small_threshold_and_max_threads() {
+    let config = SplitConfig::builder().threshold(4).max_threads(2).build().unwrap();
+    
+    let mut names: Vec<String> = config.run(0..10, |_: i64| thread::current().name().unwrap().to_string());
+    
+    names.dedup();
+    
+    // Three chunks of the threshold would be needed, but only two threads are allowed
+    assert_eq!(names, vec!["split-worker-0", "split-worker-1"]);
+    assert_eq!(config.run(0..10, |num: i64| num + 1), (1..=10).collect::<Vec<i64>>());
+}
+
+#[test]
+fn free_function_takes_config() {
+    let config = SplitConfig::builder().threshold(4).max_threads(2).build().unwrap();
+    
+    let mut names = split_comp_work_with_config((0..10).collect(), |_: i64| {
+        thread::current().name().unwrap().to_string()
+    }, &config);
+    
+    names.dedup();
+    
+    assert_eq!(names, vec!["split-worker-0", "split-worker-1"]);
+    assert_eq!(split_comp_work_with_config((0..10).collect(), |num: i64| num + 1, &config),
+        (1..=10).collect::<Vec<i64>>());
+}
+
+#[test]
+fn work_config_is_split_config() {
+    let config: WorkConfig = WorkConfig::builder().threshold(4).max_threads(2).build().unwrap();
+    
+    assert_eq!(config, SplitConfig::builder().threshold(4).max_threads(2).build().unwrap());
+    assert_eq!(split_comp_work_with_config((0..10).collect(), |num: i64| num * 2, &config),
+        (0..10).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+fn force_sequential_runs_in_current_thread() {
+    let config = SplitConfig::builder().threshold(1).max_threads(4).force_sequential(true).build().unwrap();
+    let caller = thread::current().id();
+    
+    assert!(config.force_sequential());
+    assert!(!SplitConfig::default().force_sequential());
+    
+    let thread_ids = config.run_ref(&(0..1000).collect::<Vec<i64>>(), |_: &i64| thread::current().id());
+    
+    assert!(thread_ids.iter().all(|id| *id == caller));
+    assert_eq!(config.run(0..1000, |num: i64| num * 2), (0..1000).map(|num| num * 2).collect::<Vec<i64>>());
+    assert!(config.run_with_stats((0..1000).collect(), |num: i64| num).1.sequential);
+}
@@ -0,0 +1,126 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use splitting_of_work::{default_max_threads, split_comp_map_init, split_comp_work_mut};
+use splitting_of_work::{split_comp_work_with_context, split_comp_work_with_ctx, SplitConfig};
+
+#[test]
+fn context_is_created_once_per_thread() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_contexts = AtomicUsize::new(0);
+    
+    // Context counts how many items its thread has processed
+    let result = config.run_with_ctx((0..100).collect(), || {
+        num_of_contexts.fetch_add(1, Ordering::Relaxed);
+        0_usize
+    }, |num_of_processed: &mut usize, num: i64| {
+        *num_of_processed += 1;
+        (num * 2, *num_of_processed)
+    });
+    
+    let values: Vec<i64> = result.iter().map(|(value, _)| *value).collect();
+    let counts: Vec<usize> = result.iter().map(|(_, count)| *count).collect();
+    
+    assert_eq!(values, (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+    assert_eq!(num_of_contexts.into_inner(), 4);
+    assert_eq!(counts, (0..4).flat_map(|_| 1..=25).collect::<Vec<usize>>());
+}
+
+#[test]
+fn context_on_sequential_path() {
+    let num_of_contexts = AtomicUsize::new(0);
+    
+    let result = split_comp_work_with_ctx(vec![1, 2, 3], || {
+        num_of_contexts.fetch_add(1, Ordering::Relaxed);
+        Vec::new()
+    }, |seen: &mut Vec<i64>, num: i64| {
+        seen.push(num);
+        seen.len()
+    });
+    
+    assert_eq!(result, vec![1, 2, 3]);
+    assert_eq!(num_of_contexts.into_inner(), 1);
+}
+
+#[test]
+fn context_does_not_have_to_be_send() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Shared ownership inside one thread only
+    let result = config.run_with_ctx((0..100).collect(), || Rc::new(RefCell::new(0_i64)),
+        |sum: &mut Rc<RefCell<i64>>, num: i64| {
+        *sum.borrow_mut() += num;
+        *sum.borrow()
+    });
+    
+    assert_eq!(result[24], (0..25).sum::<i64>());
+    assert_eq!(result[25], 25);
+}
+
+#[test]
+fn lookups_against_shared_dictionary() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let dictionary: Arc<HashMap<u64, String>> = Arc::new((0..300).map(|num| (num, format!("word{}", num))).collect());
+    
+    let result = config.run_with_context((0..400).rev().collect(), Arc::clone(&dictionary),
+        |dictionary: &HashMap<u64, String>, num: u64| dictionary.get(&num).cloned());
+    
+    let expected: Vec<Option<String>> = (0..400).rev().map(|num| dictionary.get(&num).cloned()).collect();
+    
+    assert_eq!(result, expected);
+    assert_eq!(result.iter().flatten().count(), 300);
+    
+    // Clones of the threads are released after the work
+    assert_eq!(Arc::strong_count(&dictionary), 1);
+}
+
+#[test]
+fn shared_context_on_sequential_path() {
+    let result = split_comp_work_with_context(vec![1, 2], Arc::new(10), |offset: &i64, num: i64| num + offset);
+    
+    assert_eq!(result, vec![11, 12]);
+}
+
+#[test]
+fn every_clone_of_stateful_function_sees_only_its_chunk() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let mut seen: Vec<i64> = Vec::new();
+    
+    let result = config.run_mut((0..100).collect(), move |num: i64| {
+        seen.push(num);
+        seen.clone()
+    });
+    
+    // The state of every thread starts empty at the first item of its chunk
+    for (index, seen) in result.iter().enumerate() {
+        let start = index / 25 * 25;
+        
+        assert_eq!(*seen, (start as i64..=index as i64).collect::<Vec<i64>>());
+    }
+}
+
+#[test]
+fn stateful_function_on_sequential_path() {
+    let mut num_of_calls = 0;
+    
+    let result = split_comp_work_mut(vec![5, 6, 7], move |num: i64| {
+        num_of_calls += 1;
+        num * num_of_calls
+    });
+    
+    assert_eq!(result, vec![5, 12, 21]);
+}
+
+#[test]
+fn split_comp_map_init_creates_context_per_thread() {
+    let contexts = AtomicUsize::new(0);
+    
+    let result = split_comp_map_init((0..1000).collect(), || contexts.fetch_add(1, Ordering::Relaxed),
+        |_: &mut usize, num: u64| num + 1);
+    
+    assert_eq!(result, (1..=1000).collect::<Vec<u64>>());
+    assert!(contexts.into_inner() <= default_max_threads());
+}
@@ -0,0 +1,37 @@
+use splitting_of_work::SplitConfig;
+
+// Pseudo-random numbers of a linear congruential generator
+fn random_numbers(seed: u64, len: usize) -> Vec<u64> {
+    let mut state = seed;
+    
+    (0..len).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state >> 17
+    }).collect()
+}
+
+// Pure function mixing bits of the number
+fn mix(num: u64) -> u64 {
+    (num ^ (num >> 31)).wrapping_mul(0xBF58476D1CE4E5B9).rotate_left(23)
+}
+
+#[test]
+fn threaded_path_matches_sequential_for_random_lengths() {
+    let sequential = SplitConfig::builder().max_threads(1).build().unwrap();
+    let lengths = random_numbers(2024, 1000);
+    
+    for (seed, len) in lengths.into_iter().enumerate() {
+        let len = (len % 500) as usize;
+        let threaded = SplitConfig::builder()
+            .threshold(1 + seed % 16)
+            .max_threads(1 + seed % 6)
+            .build()
+            .unwrap();
+        let vector = random_numbers(seed as u64, len);
+        
+        let expected = sequential.run(vector.clone(), mix);
+        
+        assert_eq!(threaded.run(vector.clone(), mix), expected, "Length {}", len);
+        assert_eq!(threaded.run_ref(&vector, |num: &u64| mix(*num)), expected, "Length {}", len);
+    }
+}
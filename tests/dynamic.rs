@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use splitting_of_work::{split_comp_work_dyn, DynFunction, SplitConfig};
+
+// Building the function from its name like from a configuration file
+fn function_by_name(name: &str) -> DynFunction<i64, i64> {
+    match name {
+        "square" => Arc::new(|num| num * num),
+        "negate" => Arc::new(|num| -num),
+        _ => panic!("Unknown function {}", name),
+    }
+}
+
+#[test]
+fn function_is_chosen_at_runtime() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for name in ["square", "negate"] {
+        let expected: Vec<i64> = (0..100).map(|num| function_by_name(name)(num)).collect();
+        
+        assert_eq!(config.run_dyn((0..100).collect(), function_by_name(name)), expected);
+    }
+}
+
+#[test]
+fn dyn_on_sequential_path() {
+    assert_eq!(split_comp_work_dyn(vec![1, 2, 3], function_by_name("negate")), vec![-1, -2, -3]);
+}
+
+#[test]
+fn stages_are_dispatched_from_registry() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let prefix = String::from("item-");
+    
+    // Closures of the registry capture their own state and share it between threads
+    let mut registry: HashMap<String, DynFunction<i64, String>> = HashMap::new();
+    registry.insert(String::from("label"), Arc::new(move |num| format!("{}{}", prefix, num)));
+    registry.insert(String::from("hex"), Arc::new(|num| format!("{:x}", num)));
+    
+    let labels = config.run_dyn((0..100).collect(), Arc::clone(&registry["label"]));
+    let hex = config.run_dyn((0..100).collect(), Arc::clone(&registry["hex"]));
+    
+    assert_eq!(labels, (0..100).map(|num| format!("item-{}", num)).collect::<Vec<String>>());
+    assert_eq!(hex, (0..100).map(|num| format!("{:x}", num)).collect::<Vec<String>>());
+    assert_eq!(Arc::strong_count(&registry["hex"]), 1);
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::io;
+
+use splitting_of_work::{split_comp_work_catching, split_comp_work_checked, SplitConfig, SplitError};
+#[allow(deprecated)]
+use splitting_of_work::WorkError;
+
+// Value on which the client's function panics
+const BAD_VALUE: i64 = 17;
+
+// Example of client's function which panics on one value
+fn panic_on_bad_value(num: i64) -> i64 {
+    if num == BAD_VALUE {
+        panic!("Bad value");
+    }
+    
+    num * 2
+}
+
+#[test]
+fn checked_returns_results() {
+    let vector: Vec<i64> = (0..34).collect();
+    
+    let result = split_comp_work_checked(vector, |num: i64| num * 2);
+    
+    assert_eq!(result.unwrap(), (0..34).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+fn worker_panic_is_reported() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..34).collect();
+    
+    let result = config.run_checked(vector, panic_on_bad_value);
+    
+    assert!(matches!(result, Err(SplitError::WorkerPanicked { chunk: 1, range }) if range == (9..18)));
+}
+
+#[test]
+#[allow(deprecated)]
+fn worker_panic_is_matched_through_former_name() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result: Result<Vec<i64>, WorkError> = config.run_checked((0..34).collect(), panic_on_bad_value);
+    
+    assert!(matches!(result, Err(WorkError::WorkerPanicked { chunk: 1, .. })));
+    assert!(matches!(WorkError::ResultsLost { missing: 3 }, SplitError::ResultsLost { missing: 3 }));
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn worker_panic_is_raised() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..34).collect();
+    
+    config.run(vector, panic_on_bad_value);
+}
+
+#[test]
+fn lost_range_is_described() {
+    let error = SplitError::WorkerPanicked { chunk: 1, range: 9..18 };
+    
+    assert_eq!(error.to_string(), "Thread doing computational work on chunk 1 (items 9..18) has panicked");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn spawn_failure_keeps_its_cause() {
+    let error = SplitError::SpawnFailed(io::Error::other("No more threads"));
+    
+    assert!(matches!(error, SplitError::SpawnFailed(ref cause) if cause.kind() == io::ErrorKind::Other));
+    assert_eq!(error.to_string(), "Can't spawn thread for computational work: No more threads");
+    assert_eq!(error.source().unwrap().to_string(), "No more threads");
+}
+
+#[test]
+fn other_errors_are_described() {
+    assert_eq!(SplitError::Cancelled.to_string(), "Computational work has been cancelled");
+    assert_eq!(SplitError::ResultsLost { missing: 3 }.to_string(), "Results of 3 items have been lost");
+    assert_eq!(SplitError::TimedOut { completed: 5 }.to_string(),
+        "Computational work has timed out after 5 completed items");
+    assert_eq!(SplitError::LengthMismatch { left: 3, right: 2 }.to_string(),
+        "Input vectors have different lengths: 3 and 2");
+}
+
+#[test]
+fn panics_of_items_are_caught() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..34).collect();
+    
+    let result = config.run_catching(vector, panic_on_bad_value);
+    
+    for (num, item) in (0..34).zip(result) {
+        match item {
+            Ok(value) => assert_eq!(value, num * 2),
+            Err(payload) => {
+                assert_eq!(num, BAD_VALUE);
+                assert_eq!(payload.downcast_ref::<&str>(), Some(&"Bad value"));
+            }
+        }
+    }
+}
+
+#[test]
+fn panics_are_caught_on_sequential_path() {
+    let result = split_comp_work_catching(vec![16, 17, 18], panic_on_bad_value);
+    
+    assert_eq!(*result[0].as_ref().unwrap(), 32);
+    assert!(result[1].is_err());
+    assert_eq!(*result[2].as_ref().unwrap(), 36);
+}
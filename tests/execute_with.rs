@@ -0,0 +1,66 @@
+use std::thread;
+
+use splitting_of_work::{execute_with, partition, Job};
+
+// Runs the jobs one after another in the current thread
+fn run_sequentially<R>(jobs: Vec<Job<'_, R>>) -> Vec<Vec<R>> {
+    jobs.into_iter().map(|job| job()).collect()
+}
+
+// Runs every job in its own scoped thread and joins them in order
+fn run_in_threads<R: Send>(jobs: Vec<Job<'_, R>>) -> Vec<Vec<R>> {
+    thread::scope(|scope| {
+        let handles: Vec<_> = jobs.into_iter().map(|job| scope.spawn(job)).collect();
+        
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    })
+}
+
+#[test]
+fn sequential_executor_keeps_order() {
+    let vector: Vec<u64> = (0..100).collect();
+    
+    let results = execute_with(vector, 8, 4, &|x: u64| x * x, run_sequentially);
+    
+    assert_eq!(results, (0..100).map(|x| x * x).collect::<Vec<u64>>());
+}
+
+#[test]
+fn threaded_executor_keeps_order() {
+    let vector: Vec<u64> = (0..1000).collect();
+    
+    let results = execute_with(vector, 8, 4, &|x: u64| x + 1, run_in_threads);
+    
+    assert_eq!(results, (1..1001).collect::<Vec<u64>>());
+}
+
+#[test]
+fn one_job_per_range() {
+    let mut sizes: Vec<usize> = Vec::new();
+    
+    execute_with((0..10).collect(), 4, 8, &|x: i32| x, |jobs| {
+        let results: Vec<Vec<i32>> = run_sequentially(jobs);
+        sizes = results.iter().map(Vec::len).collect();
+        results
+    });
+    
+    let expected: Vec<usize> = partition(10, 4, 8).into_iter().map(|range| range.len()).collect();
+    
+    assert_eq!(sizes, expected);
+}
+
+#[test]
+fn empty_vector_creates_no_jobs() {
+    let results = execute_with(Vec::<i32>::new(), 4, 8, &|x: i32| x, |jobs| {
+        assert!(jobs.is_empty());
+        run_sequentially(jobs)
+    });
+    
+    assert!(results.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Executor returned 0 results for 3 jobs")]
+fn missing_job_results_panic() {
+    execute_with((0..10).collect(), 4, 8, &|x: i32| x, |_jobs| Vec::new());
+}
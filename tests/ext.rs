@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use splitting_of_work::prelude::*;
+use splitting_of_work::split_comp_work;
+
+fn is_even(num: i64) -> bool {
+    num % 2 == 0
+}
+
+#[test]
+fn split_map_matches_split_comp_work() {
+    let vector: Vec<i64> = (0..100).collect();
+    
+    assert_eq!(vector.clone().split_map(is_even), split_comp_work(vector, is_even));
+}
+
+#[test]
+fn split_map_chains_with_iterators() {
+    let result: i64 = (1..=40).collect::<Vec<i64>>().split_map(|num| num * 2).into_iter().sum();
+    
+    assert_eq!(result, 1640);
+}
+
+#[test]
+fn par_split_map_on_vec() {
+    let vector: Vec<i64> = (0..100).collect();
+    
+    assert_eq!(vector.clone().par_split_map(is_even), split_comp_work(vector, is_even));
+}
+
+#[test]
+fn par_split_map_on_slice_of_array() {
+    let numbers: [i64; 6] = [1, 2, 3, 4, 5, 6];
+    
+    let result = numbers.as_slice().par_split_map(|num| num * 10);
+    
+    assert_eq!(result, vec![10, 20, 30, 40, 50, 60]);
+    assert_eq!(numbers, [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn par_split_map_chains_with_iterators() {
+    let numbers: Vec<i64> = (1..=40).collect();
+    
+    let result: i64 = numbers.par_split_map(|num| num * 2).into_iter().filter(|num| num % 4 == 0).sum();
+    
+    assert_eq!(result, 840);
+}
+
+#[test]
+fn par_split_for_each_on_vec_and_slice() {
+    let sum = AtomicI64::new(0);
+    let numbers: [i64; 4] = [1, 2, 3, 4];
+    
+    (1..=100).collect::<Vec<i64>>().par_split_for_each(|num| {
+        sum.fetch_add(num, Ordering::Relaxed);
+    });
+    numbers[..].par_split_for_each(|num| {
+        sum.fetch_add(*num, Ordering::Relaxed);
+    });
+    
+    assert_eq!(sum.load(Ordering::Relaxed), 5060);
+}
+
+#[test]
+fn par_split_filter_on_vec_and_slice() {
+    let numbers: [i64; 6] = [1, 2, 3, 4, 5, 6];
+    
+    assert_eq!((0..20).collect::<Vec<i64>>().par_split_filter(|num| is_even(*num)),
+        (0..20).step_by(2).collect::<Vec<i64>>());
+    assert_eq!(numbers.as_slice().par_split_filter(|num| is_even(**num)), vec![&2, &4, &6]);
+}
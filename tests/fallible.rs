@@ -0,0 +1,133 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use splitting_of_work::{split_comp_work_collect_errors, split_try_map, try_split_comp_work, SplitConfig};
+
+// Example of client's function which fails on multiples of the divisor
+fn fail_on_multiples(num: i64, divisor: i64) -> Result<i64, String> {
+    if num % divisor == 0 {
+        Err(format!("Bad value {}", num))
+    } else {
+        Ok(num * 2)
+    }
+}
+
+#[test]
+fn all_items_succeed() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (1..=100).collect();
+    
+    let result = config.try_run(vector, |num: i64| fail_on_multiples(num, 1000));
+    
+    assert_eq!(result, Ok((1..=100).map(|num| num * 2).collect()));
+}
+
+#[test]
+fn lowest_index_error_wins() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for _ in 0..20 {
+        let vector: Vec<i64> = (1..=100).collect();
+        
+        // Errors of later chunks are found faster than the error of the first chunk
+        let result = config.try_run(vector, |num: i64| {
+            if num == 13 {
+                thread::sleep(Duration::from_millis(5));
+            }
+            
+            fail_on_multiples(num, 13)
+        });
+        
+        assert_eq!(result, Err("Bad value 13".to_string()));
+    }
+}
+
+#[test]
+fn sequential_path_matches() {
+    let vector: Vec<i64> = (1..=5).collect();
+    
+    let result = try_split_comp_work(vector, |num: i64| fail_on_multiples(num, 2));
+    
+    assert_eq!(result, Err("Bad value 2".to_string()));
+}
+
+#[test]
+fn workers_stop_after_error() {
+    let config = SplitConfig::builder().max_threads(2).build().unwrap();
+    let processed = AtomicUsize::new(0);
+    let vector: Vec<i64> = (0..10_000).collect();
+    
+    let result = config.try_run(vector, |num: i64| {
+        processed.fetch_add(1, Ordering::Relaxed);
+        fail_on_multiples(num, 1)
+    });
+    
+    assert_eq!(result, Err("Bad value 0".to_string()));
+    assert!(processed.load(Ordering::Relaxed) < 10_000);
+}
+
+#[test]
+fn all_errors_are_collected_in_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (1..=100).collect();
+    
+    let result = config.run_collect_errors(vector, |num: i64| fail_on_multiples(num, 7));
+    
+    let errors_for_check: Vec<(usize, String)> = (1..=100)
+        .filter(|num| num % 7 == 0)
+        .map(|num| ((num - 1) as usize, format!("Bad value {}", num)))
+        .collect();
+    
+    assert_eq!(result, Err(errors_for_check));
+}
+
+#[test]
+fn collecting_without_errors() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (1..=100).collect();
+    
+    let result = config.run_collect_errors(vector, |num: i64| fail_on_multiples(num, 1000));
+    
+    assert_eq!(result, Ok((1..=100).map(|num| num * 2).collect()));
+}
+
+#[test]
+fn collecting_on_both_paths_is_identical() {
+    let sequential = SplitConfig::builder().max_threads(1).build().unwrap();
+    let threaded = SplitConfig::builder().threshold(2).max_threads(8).build().unwrap();
+    
+    for len in [0, 1, 5, 50, 333] {
+        let vector: Vec<i64> = (1..=len).collect();
+        
+        let sequential_result = sequential.run_collect_errors(vector.clone(), |num: i64| fail_on_multiples(num, 5));
+        let threaded_result = threaded.run_collect_errors(vector, |num: i64| fail_on_multiples(num, 5));
+        
+        assert_eq!(sequential_result, threaded_result);
+    }
+}
+
+#[test]
+fn collecting_on_sequential_path() {
+    let result = split_comp_work_collect_errors(vec![1, 2, 3], |num: i64| fail_on_multiples(num, 3));
+    
+    assert_eq!(result, Err(vec![(2, "Bad value 3".to_string())]));
+}
+
+#[test]
+fn error_on_seven_is_returned() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (1..=20).collect();
+    
+    let result = config.try_run(vector, |num: i64| if num == 7 { Err(num) } else { Ok(num * 2) });
+    
+    assert_eq!(result, Err(7));
+}
+
+#[test]
+fn split_try_map_returns_first_error() {
+    assert_eq!(split_try_map((1..1000).collect(), |num: i64| fail_on_multiples(num, 300)),
+        Err("Bad value 300".to_string()));
+    assert_eq!(split_try_map((1..100).collect(), |num: i64| fail_on_multiples(num, 300)),
+        Ok((1..100).map(|num| num * 2).collect()));
+}
@@ -0,0 +1,138 @@
+use splitting_of_work::{split_comp_filter, split_comp_filter_map, split_comp_partition, split_filter, SplitConfig};
+
+#[test]
+fn filter_even_numbers() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_filter((1..=100).collect(), |num: &i64| num % 2 == 0);
+    
+    assert_eq!(result, (1..=100).filter(|num| num % 2 == 0).collect::<Vec<i64>>());
+}
+
+#[test]
+fn filter_on_sequential_path() {
+    let result = split_filter(vec![1, 2, 3, 4], |num: &i64| *num > 2);
+    
+    assert_eq!(result, vec![3, 4]);
+}
+
+#[test]
+fn filter_keeps_nothing() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_filter((1..=100).collect(), |_: &i64| false);
+    
+    assert!(result.is_empty());
+}
+
+#[test]
+fn every_other_item_keeps_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_filter((0..1000).collect(), |num: &i64| num % 2 == 1);
+    
+    assert_eq!(result, (0..1000).filter(|num| num % 2 == 1).collect::<Vec<i64>>());
+}
+
+#[test]
+fn all_false_predicate_does_not_allocate() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_filter((0..1000).collect(), |_: &i64| false);
+    
+    assert_eq!(result.capacity(), 0);
+}
+
+#[test]
+fn sequential_and_threaded_filters_agree() {
+    let sequential = SplitConfig::builder().max_threads(1).build().unwrap();
+    let threaded = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Plain function pointer as the predicate
+    let predicate: fn(&i64) -> bool = |num| num % 3 == 0;
+    
+    for len in [0, 1, 7, 8, 9, 100] {
+        let vector: Vec<i64> = (0..len).collect();
+        
+        assert_eq!(sequential.run_filter(vector.clone(), predicate), threaded.run_filter(vector, predicate));
+    }
+}
+
+#[test]
+fn filter_map_parses_numbers() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<String> = (0..100).map(|num| if num % 3 == 0 { "x".to_string() } else { num.to_string() }).collect();
+    
+    let result = config.run_filter_map(vector, |item: String| item.parse::<i64>().ok());
+    
+    assert_eq!(result, (0..100).filter(|num| num % 3 != 0).collect::<Vec<i64>>());
+}
+
+#[test]
+fn filter_map_with_empty_output() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result: Vec<i64> = config.run_filter_map((0..100).collect(), |_: i64| None);
+    
+    assert!(result.is_empty());
+}
+
+#[test]
+fn filter_map_keeps_only_last_chunk() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_filter_map((0..100).collect(), |num: i64| (num >= 75).then_some(num * 2));
+    
+    assert_eq!(result, (75..100).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+fn filter_map_on_sequential_path() {
+    let function = |num: i64| (num % 2 == 0).then_some(num + 1);
+    
+    let sequential = split_comp_filter_map(vec![1, 2, 3, 4], function);
+    let threaded = SplitConfig::builder().max_threads(4).threshold(1).build().unwrap()
+        .run_filter_map(vec![1, 2, 3, 4], function);
+    
+    assert_eq!(sequential, vec![3, 5]);
+    assert_eq!(sequential, threaded);
+}
+
+// Pseudo-random predicate mixing bits of the number
+fn is_picked(num: &u64) -> bool {
+    num.wrapping_mul(0x9E3779B97F4A7C15).rotate_left(17).is_multiple_of(3)
+}
+
+#[test]
+fn partition_matches_iterator_with_random_predicate() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<u64> = (0..5000).collect();
+    
+    let expected: (Vec<u64>, Vec<u64>) = vector.iter().partition(|num| is_picked(num));
+    
+    assert_eq!(config.run_partition(vector, is_picked), expected);
+}
+
+#[test]
+fn partition_degenerate_cases() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..100).collect();
+    
+    assert_eq!(config.run_partition(vector.clone(), |_| true), (vector.clone(), Vec::new()));
+    assert_eq!(config.run_partition(vector.clone(), |_| false), (Vec::new(), vector));
+    assert_eq!(split_comp_partition(Vec::<i64>::new(), |_| true), (Vec::new(), Vec::new()));
+}
+
+#[test]
+fn partition_below_threshold_keeps_order() {
+    let (even, odd) = split_comp_partition(vec![5, 2, 8, 3, 4], |num: &i64| num % 2 == 0);
+    
+    assert_eq!((even, odd), (vec![2, 8, 4], vec![5, 3]));
+}
+
+#[test]
+fn split_comp_filter_keeps_relative_order() {
+    let result = split_comp_filter((0..1000).collect(), |num: &u64| num % 7 == 3);
+    
+    assert_eq!(result, (0..1000).filter(|num| num % 7 == 3).collect::<Vec<u64>>());
+}
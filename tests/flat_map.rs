@@ -0,0 +1,48 @@
+use splitting_of_work::{split_comp_flat_map, split_flat_map, SplitConfig};
+
+#[test]
+fn item_expands_to_its_copies() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_flat_map((0..50).collect(), |num: usize| vec![num; num]);
+    
+    assert_eq!(result.len(), (0..50).sum::<usize>());
+    assert_eq!(result, (0..50).flat_map(|num| vec![num; num]).collect::<Vec<usize>>());
+}
+
+#[test]
+fn chunk_without_results_is_skipped() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Only items of the first and the last chunks produce results
+    let result = config.run_flat_map((0..100).collect(), |num: i64| {
+        if (25..75).contains(&num) { Vec::new() } else { vec![num, -num] }
+    });
+    
+    let expected: Vec<i64> = (0..25).chain(75..100).flat_map(|num| [num, -num]).collect();
+    
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn flat_map_on_sequential_path() {
+    let result = split_comp_flat_map(vec!["a b", "", "c"], |line: &str| line.split_whitespace());
+    
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn outputs_of_item_come_before_outputs_of_next_item() {
+    let config = SplitConfig::builder().threshold(1).max_threads(4).build().unwrap();
+    
+    let result = config.run_flat_map((1..=4).collect(), |num: usize| vec![num; num]);
+    
+    assert_eq!(result, vec![1, 2, 2, 3, 3, 3, 4, 4, 4, 4]);
+}
+
+#[test]
+fn split_flat_map_matches_iterator() {
+    let result = split_flat_map((0..1000).collect(), |num: u32| [num, num + 1]);
+    
+    assert_eq!(result, (0..1000).flat_map(|num| [num, num + 1]).collect::<Vec<u32>>());
+}
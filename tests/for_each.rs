@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use splitting_of_work::{split_comp_for_each, split_for_each, SplitConfig};
+
+#[test]
+fn every_item_is_visited_once() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let visited = Mutex::new(Vec::new());
+    
+    config.run_for_each((0..100).collect(), |num: i64| visited.lock().unwrap().push(num));
+    
+    let mut visited = visited.into_inner().unwrap();
+    visited.sort();
+    
+    assert_eq!(visited, (0..100).collect::<Vec<i64>>());
+}
+
+#[test]
+fn atomic_counter_reaches_length() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for len in [0, 1, 7, 8, 9, 1000] {
+        let count = AtomicUsize::new(0);
+        
+        config.run_for_each(vec![(); len], |_| {
+            count.fetch_add(1, Ordering::Relaxed);
+        });
+        
+        assert_eq!(count.into_inner(), len);
+    }
+}
+
+#[test]
+fn split_for_each_counts_every_item() {
+    let count = AtomicUsize::new(0);
+    
+    split_for_each(vec![(); 1000], |_| {
+        count.fetch_add(1, Ordering::Relaxed);
+    });
+    
+    assert_eq!(count.into_inner(), 1000);
+}
+
+#[test]
+fn small_input_runs_inline() {
+    let thread_ids = Mutex::new(HashSet::new());
+    
+    split_comp_for_each(vec![1, 2, 3], |_: i64| {
+        thread_ids.lock().unwrap().insert(thread::current().id());
+    });
+    
+    assert_eq!(thread_ids.into_inner().unwrap(), HashSet::from([thread::current().id()]));
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn worker_panic_is_raised() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    config.run_for_each((0..100).collect(), |num: i64| {
+        if num == 50 {
+            panic!("Bad value");
+        }
+    });
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+use std::thread::{self, ThreadId};
+
+use splitting_of_work::prelude::*;
+use splitting_of_work::{init_global_pool, split_comp_work, SplitError};
+
+// Number of threads of the global pool in these tests
+const NUM_OF_THREADS: usize = 4;
+
+// Creating the global pool of the same size whichever test runs first
+fn init() {
+    let _ = init_global_pool(NUM_OF_THREADS);
+}
+
+// Distinct threads which have processed the items
+fn threads_of(vector: Vec<u64>) -> HashSet<ThreadId> {
+    split_comp_work(vector, |_: u64| thread::current().id()).into_iter().collect()
+}
+
+#[test]
+fn threads_are_reused_between_calls() {
+    init();
+    
+    let mut all_threads: HashSet<ThreadId> = HashSet::new();
+    
+    for _ in 0..20 {
+        let threads = threads_of((0..100).collect());
+        
+        assert!(!threads.contains(&thread::current().id()));
+        all_threads.extend(threads);
+    }
+    
+    assert!(all_threads.len() <= NUM_OF_THREADS, "{} threads used", all_threads.len());
+}
+
+#[test]
+fn threads_of_pool_are_named() {
+    init();
+    
+    let names = split_comp_work((0..100).collect::<Vec<u64>>(), |_: u64| {
+        thread::current().name().map(String::from)
+    });
+    
+    assert!(names.iter().all(|name| name.as_deref().unwrap_or("").starts_with("split-worker-")));
+}
+
+#[test]
+fn small_input_stays_in_current_thread() {
+    init();
+    
+    assert_eq!(threads_of((0..7).collect()), HashSet::from([thread::current().id()]));
+}
+
+#[test]
+fn results_keep_order() {
+    init();
+    
+    let result = split_comp_work((0..10_000).collect::<Vec<u64>>(), |num: u64| num * 3);
+    
+    assert_eq!(result, (0..10_000).map(|num| num * 3).collect::<Vec<u64>>());
+}
+
+#[test]
+fn concurrent_callers_get_own_results() {
+    init();
+    
+    let callers: Vec<_> = (0..8u64).map(|caller| thread::spawn(move || {
+        for round in 0..50 {
+            let result = split_comp_work((0..500).collect::<Vec<u64>>(), move |num: u64| num * caller + round);
+            
+            assert_eq!(result, (0..500).map(|num| num * caller + round).collect::<Vec<u64>>());
+        }
+    })).collect();
+    
+    for caller in callers {
+        caller.join().unwrap();
+    }
+}
+
+#[test]
+fn nested_calls_dont_deadlock() {
+    init();
+    
+    let result = split_comp_work((0..20).collect::<Vec<u64>>(), |num: u64| {
+        split_comp_work((0..100).collect::<Vec<u64>>(), move |inner: u64| inner * num).into_iter().sum::<u64>()
+    });
+    
+    assert_eq!(result, (0..20).map(|num| 4950 * num).collect::<Vec<u64>>());
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn panic_is_resumed() {
+    init();
+    
+    split_comp_work((0..100).collect::<Vec<u64>>(), |num: u64| {
+        if num == 50 {
+            panic!("Bad value");
+        }
+        num
+    });
+}
+
+#[test]
+fn second_init_is_an_error() {
+    init();
+    
+    assert!(matches!(init_global_pool(2), Err(SplitError::PoolAlreadyInitialized)));
+}
+
+#[test]
+fn split_map_uses_global_pool() {
+    init();
+    
+    let mut all_threads = threads_of((0..100).collect());
+    
+    for _ in 0..20 {
+        let threads = (0..100).collect::<Vec<u64>>().split_map(|_| thread::current().id());
+        all_threads.extend(threads);
+    }
+    
+    assert!(all_threads.len() <= NUM_OF_THREADS, "{} threads used", all_threads.len());
+}
@@ -0,0 +1,17 @@
+use std::thread;
+
+use splitting_of_work::{init_global_pool, split_comp_work};
+
+// The only test of this binary, so nothing else creates the global pool before it
+#[test]
+fn pool_is_created_once() {
+    
+    // Work below the threshold doesn't create the pool
+    assert_eq!(split_comp_work(vec![1, 2, 3], |num: i64| num * 2), vec![2, 4, 6]);
+    
+    let callers: Vec<_> = (0..8).map(|_| thread::spawn(|| init_global_pool(3).is_ok())).collect();
+    let num_of_created = callers.into_iter().map(|caller| caller.join().unwrap()).filter(|created| *created).count();
+    
+    assert_eq!(num_of_created, 1);
+    assert!(init_global_pool(3).is_err());
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use splitting_of_work::{split_comp_count_by, split_comp_group_by, SplitConfig};
+
+// Grouping in the current thread for comparison
+fn sequential_groups(vector: &[u64], modulus: u64) -> HashMap<u64, Vec<u64>> {
+    let mut groups: HashMap<u64, Vec<u64>> = HashMap::new();
+    
+    for num in vector {
+        groups.entry(num % modulus).or_default().push(num * 2);
+    }
+    
+    groups
+}
+
+#[test]
+fn groups_match_sequential_grouping() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<u64> = (0..1000).map(|num| (num * 7919) % 1009).collect();
+    
+    let groups = config.run_group_by(vector.clone(), |num: &u64| num % 13, |num: u64| num * 2);
+    
+    assert_eq!(groups, sequential_groups(&vector, 13));
+}
+
+#[test]
+fn empty_input_has_no_groups() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert!(config.run_group_by(Vec::<u64>::new(), |num: &u64| *num, |num: u64| num).is_empty());
+    assert!(split_comp_group_by(Vec::<u64>::new(), |num: &u64| *num, |num: u64| num).is_empty());
+}
+
+#[test]
+fn all_items_share_one_key() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let groups = config.run_group_by((0..100).collect(), |_: &u64| "all", |num: u64| num);
+    
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups["all"], (0..100).collect::<Vec<u64>>());
+}
+
+#[test]
+fn every_item_has_unique_key() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let groups = config.run_group_by((0..100).collect(), |num: &u64| *num, |num: u64| num + 1);
+    
+    assert_eq!(groups.len(), 100);
+    assert!(groups.iter().all(|(key, results)| *results == vec![key + 1]));
+}
+
+#[test]
+fn count_by_matches_sequential_histogram() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<u64> = (0..1000).map(|num| (num * 7919) % 1009).collect();
+    
+    let mut expected: HashMap<u64, usize> = HashMap::new();
+    
+    for num in &vector {
+        *expected.entry(num % 13).or_default() += 1;
+    }
+    
+    assert_eq!(config.run_count_by(vector, |num: &u64| num % 13), expected);
+}
+
+#[test]
+fn count_by_of_empty_input() {
+    assert!(split_comp_count_by(Vec::<u64>::new(), |num: &u64| *num).is_empty());
+}
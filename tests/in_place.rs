@@ -0,0 +1,74 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use splitting_of_work::{split_comp_map_in_place, split_comp_work_in_place, SplitConfig};
+
+#[test]
+fn squares_in_place() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let mut data: Vec<i64> = (0..1000).collect();
+    
+    config.run_in_place(&mut data, |num: &mut i64| *num *= *num);
+    
+    assert_eq!(data, (0..1000).map(|num| num * num).collect::<Vec<i64>>());
+}
+
+#[test]
+fn in_place_on_sequential_path() {
+    let mut data = [1, 2, 3];
+    
+    split_comp_work_in_place(&mut data, |num: &mut i64| *num *= *num);
+    
+    assert_eq!(data, [1, 4, 9]);
+}
+
+#[test]
+fn in_place_on_part_of_slice() {
+    let config = SplitConfig::builder().threshold(2).max_threads(3).build().unwrap();
+    let mut data: Vec<i64> = vec![0; 20];
+    
+    config.run_in_place(&mut data[5..15], |num: &mut i64| *num += 1);
+    
+    let data_for_check: Vec<i64> = (0..20).map(|index| if (5..15).contains(&index) { 1 } else { 0 }).collect();
+    
+    assert_eq!(data, data_for_check);
+}
+
+#[test]
+fn every_item_is_visited_exactly_once() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_calls = AtomicUsize::new(0);
+    let mut visits: Vec<u32> = vec![0; 1001];
+    
+    config.run_in_place(&mut visits, |visit: &mut u32| {
+        num_of_calls.fetch_add(1, Ordering::Relaxed);
+        *visit += 1;
+    });
+    
+    assert!(visits.iter().all(|visit| *visit == 1));
+    assert_eq!(num_of_calls.into_inner(), 1001);
+}
+
+#[test]
+fn vector_of_caller_is_usable_before_and_after() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let mut values: Vec<f64> = (1..=100).map(f64::from).collect();
+    
+    let max = values.iter().cloned().fold(f64::MIN, f64::max);
+    
+    config.run_in_place(&mut values, |value: &mut f64| *value /= max);
+    
+    values.push(0.0);
+    
+    assert_eq!(values.len(), 101);
+    assert_eq!(values[99], 1.0);
+    assert_eq!(values[49], 0.5);
+}
+
+#[test]
+fn split_comp_map_in_place_updates_every_item() {
+    let mut data: Vec<u64> = (0..1000).collect();
+    
+    split_comp_map_in_place(&mut data, |num: &mut u64| *num *= 2);
+    
+    assert_eq!(data, (0..1000).map(|num| num * 2).collect::<Vec<u64>>());
+}
@@ -0,0 +1,38 @@
+use splitting_of_work::{split_comp_work_indexed, SplitConfig};
+
+#[test]
+fn indices_are_global() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_indexed(vec![1000; 100], |index: usize, num: i64| index as i64 + num);
+    
+    assert_eq!(result, (1000..1100).collect::<Vec<i64>>());
+}
+
+#[test]
+fn sequential_and_threaded_paths_match() {
+    let sequential = SplitConfig::builder().max_threads(1).build().unwrap();
+    let threaded = SplitConfig::builder().threshold(1).max_threads(4).build().unwrap();
+    
+    for len in 0..50 {
+        let vector: Vec<i64> = (0..len).collect();
+        let function = |index: usize, num: i64| num * 3 - index as i64;
+        
+        assert_eq!(threaded.run_indexed(vector.clone(), function), sequential.run_indexed(vector, function));
+    }
+}
+
+#[test]
+fn indexed_on_sequential_path() {
+    assert_eq!(split_comp_work_indexed(vec!["a", "b"], |index: usize, item: &str| format!("{}{}", item, index)),
+        vec!["a0", "b1"]);
+}
+
+#[test]
+fn index_times_value() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_indexed(vec![3; 40], |index: usize, num: usize| index * num);
+    
+    assert_eq!(result, (0..40).map(|index| index * 3).collect::<Vec<usize>>());
+}
@@ -0,0 +1,177 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+
+use splitting_of_work::{split_comp_work_into, split_comp_work_ref, split_comp_work_reuse, SplitConfig, SplitError};
+
+// Allocator counting allocations of every thread separately, so tests running in
+// parallel don't disturb each other
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Number of allocations made by the current thread so far
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+// Configuration allowing several threads on any hardware
+fn config() -> SplitConfig {
+    SplitConfig::builder().max_threads(4).build().unwrap()
+}
+
+#[test]
+fn writes_results_in_threads() {
+    let vector: Vec<i64> = (0..1000).collect();
+    let mut out = vec![0; 1000];
+    
+    config().run_into(&vector, |num| num * num, &mut out).unwrap();
+    
+    assert_eq!(out, split_comp_work_ref(&vector, |num: &i64| num * num));
+}
+
+#[test]
+fn writes_results_in_current_thread() {
+    let mut out = vec![String::new(); 3];
+    
+    split_comp_work_into(&[1, 2, 3], |num: &i32| num.to_string(), &mut out).unwrap();
+    
+    assert_eq!(out, vec!["1", "2", "3"]);
+}
+
+#[test]
+fn length_mismatch_is_an_error() {
+    let mut out = vec![-1; 5];
+    
+    let error = split_comp_work_into(&[1, 2, 3], |num: &i64| *num, &mut out).unwrap_err();
+    
+    assert!(matches!(error, SplitError::LengthMismatch { left: 3, right: 5 }));
+    assert_eq!(out, vec![-1; 5]);
+}
+
+#[test]
+fn empty_slices() {
+    let mut out: Vec<i64> = Vec::new();
+    
+    assert!(split_comp_work_into(&[], |num: &i64| *num, &mut out).is_ok());
+}
+
+#[test]
+fn buffers_are_reused_without_allocations() {
+    let vector: Vec<u64> = (0..5).collect();
+    let mut out = vec![0; 5];
+    let config = config();
+    
+    // Warming up
+    config.run_into(&vector, |num| num + 1, &mut out).unwrap();
+    
+    let before = allocations();
+    
+    for round in 0..1000 {
+        config.run_into(&vector, |num| num * round, &mut out).unwrap();
+    }
+    
+    assert_eq!(allocations(), before);
+    assert_eq!(out, vec![0, 999, 1998, 2997, 3996]);
+}
+
+#[test]
+fn buffers_are_reused_in_threads() {
+    let vector: Vec<u64> = (0..100).collect();
+    let mut out = vec![0; 100];
+    let config = config();
+    
+    for round in 0..1000 {
+        config.run_into(&vector, |num| num + round, &mut out).unwrap();
+    }
+    
+    assert_eq!(out, (999..1099).collect::<Vec<u64>>());
+}
+
+#[test]
+fn reuse_alternates_between_paths() {
+    let small: Vec<u64> = (0..4).collect();
+    let large: Vec<u64> = (0..10_000).collect();
+    let config = config();
+    let mut out: Vec<u64> = Vec::new();
+    
+    for round in 0..10 {
+        config.run_reuse(&small, |num| num + round, &mut out);
+        assert_eq!(out, (round..round + 4).collect::<Vec<u64>>());
+        
+        config.run_reuse(&large, |num| num * round, &mut out);
+        assert_eq!(out, (0..10_000).map(|num| num * round).collect::<Vec<u64>>());
+    }
+    
+    assert!(out.capacity() >= 10_000);
+}
+
+#[test]
+fn reuse_keeps_capacity_on_sequential_path() {
+    let vector: Vec<u64> = (0..5).collect();
+    let mut out: Vec<u64> = Vec::with_capacity(100);
+    let ptr = out.as_ptr();
+    
+    let before = allocations();
+    
+    for round in 0..1000 {
+        split_comp_work_reuse(&vector, |num| num ^ round, &mut out);
+    }
+    
+    assert_eq!(allocations(), before);
+    assert_eq!(out.as_ptr(), ptr);
+    assert_eq!(out.len(), 5);
+}
+
+#[test]
+fn reuse_leaves_vector_empty_after_panic() {
+    for len in [4, 10_000] {
+        let vector: Vec<u64> = (0..len).collect();
+        let mut out: Vec<u64> = vec![1, 2, 3];
+        
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            config().run_reuse(&vector, |num| if *num == len - 2 { panic!("Bad value") } else { *num }, &mut out);
+        }));
+        
+        assert!(outcome.is_err());
+        assert!(out.is_empty(), "Stale results left for {} items", len);
+    }
+}
+
+#[test]
+fn reuse_writes_in_place_on_parallel_path() {
+    let small: Vec<u64> = (0..1000).collect();
+    let large: Vec<u64> = (0..100_000).collect();
+    let config = config();
+    let mut out: Vec<u64> = Vec::with_capacity(100_000);
+    let ptr = out.as_ptr();
+    
+    // Allocations of the current thread depend on the number of threads, not of items
+    let before = allocations();
+    config.run_reuse(&small, |num| num * 2, &mut out);
+    let small_allocations = allocations() - before;
+    
+    let before = allocations();
+    config.run_reuse(&large, |num| num * 2, &mut out);
+    let large_allocations = allocations() - before;
+    
+    assert_eq!(small_allocations, large_allocations);
+    assert_eq!(out.as_ptr(), ptr);
+    assert_eq!(out, (0..100_000).map(|num| num * 2).collect::<Vec<u64>>());
+}
@@ -0,0 +1,89 @@
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::panic;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use splitting_of_work::{split_comp_work_iter, SplitConfig};
+
+#[test]
+fn long_range_is_split() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let thread_ids = Mutex::new(HashSet::new());
+    
+    let result = config.run_iter(0..100_000_i64, |num: i64| {
+        thread_ids.lock().unwrap().insert(thread::current().id());
+        num * 2
+    });
+    
+    assert_eq!(result, (0..100_000).map(|num| num * 2).collect::<Vec<i64>>());
+    assert!(!thread_ids.into_inner().unwrap().contains(&thread::current().id()));
+}
+
+#[test]
+fn short_iterator_is_sequential() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let thread_ids = Mutex::new(HashSet::new());
+    
+    let result = config.run_iter(vec![1, 2, 3], |num: i64| {
+        thread_ids.lock().unwrap().insert(thread::current().id());
+        num + 1
+    });
+    
+    assert_eq!(result, vec![2, 3, 4]);
+    assert_eq!(thread_ids.into_inner().unwrap(), HashSet::from([thread::current().id()]));
+}
+
+#[test]
+fn generator_stays_in_calling_thread() {
+    let config = SplitConfig::builder().max_threads(2).build().unwrap();
+    let num_of_pulled = Cell::new(0);
+    
+    // Generator which counts the pulled items in the calling thread
+    let generator = (0..1000_i64).inspect(|_| num_of_pulled.set(num_of_pulled.get() + 1));
+    
+    let result = config.run_iter(generator, |num: i64| num - 1);
+    
+    assert_eq!(result, (-1..999).collect::<Vec<i64>>());
+    assert_eq!(num_of_pulled.get(), 1000);
+}
+
+#[test]
+fn empty_iterator() {
+    let result: Vec<i64> = split_comp_work_iter(std::iter::empty(), |num: i64| num);
+    
+    assert!(result.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn worker_panic_is_raised() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    config.run_iter(0..1000_i64, |num: i64| {
+        if num == 500 {
+            panic!("Bad value");
+        }
+        
+        num
+    });
+}
+
+#[test]
+fn panic_of_all_threads_doesnt_hang() {
+    let (sender, receiver) = mpsc::channel();
+    
+    // Watching the work from another thread, so a hang fails the test instead of blocking it
+    thread::spawn(move || {
+        let config = SplitConfig::builder().max_threads(2).build().unwrap();
+        
+        let outcome = panic::catch_unwind(|| {
+            config.run_iter(0..100_000_u64, |_: u64| -> u64 { panic!("Every item fails") })
+        });
+        
+        let _ = sender.send(outcome.is_err());
+    });
+    
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(30)), Ok(true));
+}
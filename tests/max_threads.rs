@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+use splitting_of_work::{default_max_threads, split_comp_work_with_max_threads, MAX_NUM_OF_THREADS};
+
+// Runs the work and returns results together with ids of threads that did it
+fn run_with_max_threads(len: usize, max_threads: usize) -> (Vec<usize>, HashSet<ThreadId>) {
+    let thread_ids = Arc::new(Mutex::new(HashSet::new()));
+    let thread_ids_copy = Arc::clone(&thread_ids);
+    
+    let vector: Vec<usize> = (0..len).collect();
+    
+    let result = split_comp_work_with_max_threads(vector, move |num: usize| {
+        thread_ids_copy.lock().unwrap().insert(thread::current().id());
+        num + 1
+    }, max_threads);
+    
+    let thread_ids = thread_ids.lock().unwrap().clone();
+    
+    (result, thread_ids)
+}
+
+#[test]
+fn two_threads_at_most() {
+    let (result, thread_ids) = run_with_max_threads(34, 2);
+    
+    assert_eq!(result, (1..=34).collect::<Vec<usize>>());
+    assert!(thread_ids.len() <= 2);
+    assert!(!thread_ids.contains(&thread::current().id()));
+}
+
+#[test]
+fn one_thread_is_sequential() {
+    let (result, thread_ids) = run_with_max_threads(1000, 1);
+    
+    assert_eq!(result, (1..=1000).collect::<Vec<usize>>());
+    assert_eq!(thread_ids, HashSet::from([thread::current().id()]));
+}
+
+#[test]
+fn large_input_is_capped() {
+    for max_threads in 2..=8 {
+        let (result, thread_ids) = run_with_max_threads(10_000, max_threads);
+        
+        assert_eq!(result, (1..=10_000).collect::<Vec<usize>>());
+        assert!(thread_ids.len() <= max_threads);
+    }
+}
+
+#[test]
+fn zero_threads_use_default() {
+    let (result, thread_ids) = run_with_max_threads(10_000, 0);
+    
+    assert_eq!(result, (1..=10_000).collect::<Vec<usize>>());
+    assert!(thread_ids.len() <= default_max_threads());
+}
+
+#[test]
+fn default_max_threads_is_bounded() {
+    let max_threads = default_max_threads();
+    
+    assert!(max_threads >= 1);
+    assert!(max_threads <= MAX_NUM_OF_THREADS);
+}
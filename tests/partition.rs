@@ -0,0 +1,106 @@
+use std::thread::{self, ThreadId};
+
+use splitting_of_work::{partition, SplitConfig};
+
+// Maximum number of threads used by the tests
+const MAX_THREADS: usize = 4;
+
+// Runs the work and returns sizes of chunks, one chunk per thread in input order
+fn chunk_sizes(len: usize) -> Vec<usize> {
+    let config = SplitConfig::builder().max_threads(MAX_THREADS).build().unwrap();
+    let vector: Vec<usize> = (0..len).collect();
+    
+    let thread_ids: Vec<ThreadId> = config.run_ref(&vector, |_: &usize| thread::current().id());
+    
+    // Items of one chunk are contiguous and processed by the same thread
+    let mut sizes: Vec<usize> = Vec::new();
+    
+    for (index, thread_id) in thread_ids.iter().enumerate() {
+        if index > 0 && thread_ids[index - 1] == *thread_id {
+            *sizes.last_mut().unwrap() += 1;
+        } else {
+            sizes.push(1);
+        }
+    }
+    
+    sizes
+}
+
+#[test]
+fn chunks_are_balanced() {
+    for len in 9..=100 {
+        let sizes = chunk_sizes(len);
+        
+        let smallest = *sizes.iter().min().unwrap();
+        let largest = *sizes.iter().max().unwrap();
+        
+        assert_eq!(sizes.iter().sum::<usize>(), len);
+        assert!(smallest > 0, "Empty chunk for {} items: {:?}", len, sizes);
+        assert!(largest - smallest <= 1, "Unbalanced chunks for {} items: {:?}", len, sizes);
+        assert!(sizes.len() <= MAX_THREADS);
+    }
+}
+
+#[test]
+fn larger_chunks_come_first() {
+    assert_eq!(chunk_sizes(10), vec![5, 5]);
+    assert_eq!(chunk_sizes(30), vec![8, 8, 7, 7]);
+    assert_eq!(chunk_sizes(33), vec![9, 8, 8, 8]);
+}
+
+#[test]
+fn partition_of_edge_sizes() {
+    assert_eq!(partition(0, 8, 4), Vec::new());
+    assert_eq!(partition(1, 8, 4), vec![0..1]);
+    assert_eq!(partition(7, 8, 4), vec![0..7]);
+    assert_eq!(partition(8, 8, 4), vec![0..8]);
+    assert_eq!(partition(9, 8, 4), vec![0..5, 5..9]);
+    assert_eq!(partition(64, 8, 4), vec![0..16, 16..32, 32..48, 48..64]);
+    assert_eq!(partition(65, 8, 4), vec![0..17, 17..33, 33..49, 49..65]);
+    assert_eq!(partition(64, 8, 16).len(), 8);
+    assert_eq!(partition(65, 8, 16).len(), 9);
+}
+
+#[test]
+fn partition_without_threads_is_empty() {
+    assert_eq!(partition(10, 8, 0), Vec::new());
+}
+
+#[test]
+fn partition_covers_all_indices() {
+    for len in 0..200 {
+        for max_threads in 1..10 {
+            let ranges = partition(len, 8, max_threads);
+            
+            let sizes: Vec<usize> = ranges.iter().map(|range| range.len()).collect();
+            
+            assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+            assert_eq!(sizes.iter().sum::<usize>(), len);
+            assert!(sizes.iter().all(|&size| size > 0));
+            assert!(sizes.iter().max().unwrap_or(&0) - sizes.iter().min().unwrap_or(&0) <= 1);
+        }
+    }
+}
+
+#[test]
+fn number_of_ranges_is_integer_ceiling() {
+    for threshold in 1..20 {
+        for len in 1..500 {
+            let expected = len / threshold + usize::from(len % threshold != 0);
+            
+            assert_eq!(partition(len, threshold, usize::MAX).len(), expected,
+                "Wrong number of ranges for {} items with threshold {}", len, threshold);
+        }
+    }
+}
+
+#[test]
+fn partition_of_huge_lengths() {
+    // Lengths beyond 2^53 aren't exact as floats, the ceiling must still be exact
+    let len = (1 << 53) + 1;
+    
+    assert_eq!(partition(len, 1 << 52, 64).len(), 3);
+    assert_eq!(partition(usize::MAX, usize::MAX, 64), vec![0..usize::MAX]);
+    assert_eq!(partition(usize::MAX, usize::MAX - 1, 64).len(), 2);
+    assert_eq!(partition(usize::MAX, 1, 4).last().unwrap().end, usize::MAX);
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use splitting_of_work::{WorkPool, WorkerPool};
+
+#[test]
+fn pool_is_reused() {
+    let pool = WorkPool::new(4);
+    let thread_ids = Arc::new(Mutex::new(HashSet::new()));
+    
+    for _ in 0..100 {
+        let thread_ids_copy = Arc::clone(&thread_ids);
+        
+        let result = pool.map((0..100).collect(), move |num: i64| {
+            thread_ids_copy.lock().unwrap().insert(thread::current().id());
+            num * 2
+        });
+        
+        assert_eq!(result, (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+    }
+    
+    assert_eq!(pool.num_of_threads(), 4);
+    assert!(thread_ids.lock().unwrap().len() <= 4);
+}
+
+#[test]
+fn small_input_is_sequential() {
+    let pool = WorkPool::new(4);
+    let caller_id = thread::current().id();
+    
+    let result = pool.map(vec![1, 2, 3], move |_: i64| thread::current().id() == caller_id);
+    
+    assert_eq!(result, vec![true, true, true]);
+}
+
+#[test]
+fn pool_is_shared_between_callers() {
+    let pool = Arc::new(WorkPool::new(3));
+    
+    let callers: Vec<_> = (0..4).map(|caller| {
+        let pool_copy = Arc::clone(&pool);
+        
+        thread::spawn(move || {
+            for _ in 0..20 {
+                let result = pool_copy.map((0..500).collect(), move |num: i64| num + caller);
+                
+                assert_eq!(result, (0..500).map(|num| num + caller).collect::<Vec<i64>>());
+            }
+        })
+    }).collect();
+    
+    for caller in callers {
+        caller.join().unwrap();
+    }
+}
+
+#[test]
+fn pool_survives_panic() {
+    let pool = WorkPool::new(2);
+    
+    let result = thread::scope(|scope| {
+        scope.spawn(|| pool.map((0..100).collect(), |num: i64| if num == 70 { panic!("Bad value") } else { num })).join()
+    });
+    
+    assert!(result.is_err());
+    
+    let result = pool.map((0..100).collect(), |num: i64| num);
+    
+    assert_eq!(result, (0..100).collect::<Vec<i64>>());
+}
+
+#[test]
+#[should_panic(expected = "Maximum number of threads must be at least one")]
+fn empty_pool_is_rejected() {
+    WorkPool::new(0);
+}
+
+#[test]
+fn many_calls_reuse_threads_of_pool() {
+    let pool = WorkerPool::new(4);
+    let caller_id = thread::current().id();
+    let thread_ids = Arc::new(Mutex::new(HashSet::new()));
+    let vector: Vec<u64> = (0..1000).collect();
+    let expected: Vec<u64> = vector.iter().map(|num| num * 2).collect();
+    
+    for _ in 0..1000 {
+        let thread_ids_copy = Arc::clone(&thread_ids);
+        
+        let result = pool.split_comp_work(vector.clone(), move |num: u64| {
+            thread_ids_copy.lock().unwrap().insert(thread::current().id());
+            num * 2
+        });
+        
+        assert_eq!(result, expected);
+    }
+    
+    // Spawning threads per call would give a new thread id to every chunk of every call
+    let thread_ids = thread_ids.lock().unwrap();
+    
+    assert!(thread_ids.len() <= pool.num_of_threads());
+    assert!(!thread_ids.contains(&caller_id));
+}
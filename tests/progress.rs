@@ -0,0 +1,63 @@
+use std::cell::RefCell;
+use std::thread;
+
+use splitting_of_work::{split_comp_work_with_progress, SplitConfig};
+
+#[test]
+fn progress_is_reported_for_every_item() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let reports = RefCell::new(Vec::new());
+    
+    let result = config.run_with_progress((0..100).collect(), |num: i64| num * 2,
+        |completed, total| reports.borrow_mut().push((completed, total)));
+    
+    assert_eq!(result, (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+    assert_eq!(reports.into_inner(), (1..=100).map(|completed| (completed, 100)).collect::<Vec<_>>());
+}
+
+#[test]
+fn progress_is_reported_on_sequential_path() {
+    let reports = RefCell::new(Vec::new());
+    
+    let result = split_comp_work_with_progress(vec![1, 2, 3], |num: i64| num + 1,
+        |completed, total| reports.borrow_mut().push((completed, total)));
+    
+    assert_eq!(result, vec![2, 3, 4]);
+    assert_eq!(reports.into_inner(), vec![(1, 3), (2, 3), (3, 3)]);
+}
+
+#[test]
+fn progress_is_reported_from_calling_thread() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let calling_thread = thread::current().id();
+    
+    config.run_with_progress((0..100).collect(), |num: i64| num, |_, _| {
+        assert_eq!(thread::current().id(), calling_thread);
+    });
+}
+
+#[test]
+fn no_progress_for_empty_vector() {
+    let reports = RefCell::new(Vec::new());
+    
+    let result: Vec<i64> = split_comp_work_with_progress(Vec::new(), |num: i64| num,
+        |completed, total| reports.borrow_mut().push((completed, total)));
+    
+    assert!(result.is_empty());
+    assert!(reports.into_inner().is_empty());
+}
+
+#[test]
+fn progress_is_called_once_per_item() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for len in [1, 7, 8, 9, 34, 1000] {
+        let completed_counts = RefCell::new(Vec::new());
+        
+        config.run_with_progress((0..len as i64).collect(), |num: i64| num, |completed, _| {
+            completed_counts.borrow_mut().push(completed);
+        });
+        
+        assert_eq!(completed_counts.into_inner(), (1..=len).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,61 @@
+use splitting_of_work::{split_comp_fold, split_reduce, SplitConfig};
+
+#[test]
+fn sum_of_squares() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let sum = config.run_reduce((1..=1000).collect(), |num: i64| num * num, |a, b| a + b, 0);
+    
+    assert_eq!(sum, 1000 * 1001 * 2001 / 6);
+}
+
+#[test]
+fn reduce_on_sequential_path() {
+    let max = split_reduce(vec![3, 9, 4], |num: i64| num, i64::max, i64::MIN);
+    
+    assert_eq!(max, 9);
+}
+
+#[test]
+fn reduce_empty_vector() {
+    let sum = split_reduce(Vec::new(), |num: i64| num, |a, b| a + b, 0);
+    
+    assert_eq!(sum, 0);
+}
+
+#[test]
+fn concatenation_keeps_order() {
+    let config = SplitConfig::builder().threshold(3).max_threads(5).build().unwrap();
+    
+    let text = config.run_reduce((0..50).collect(), |num: i64| num.to_string(), |a, b| a + &b, String::new());
+    
+    assert_eq!(text, (0..50).map(|num| num.to_string()).collect::<String>());
+}
+
+#[test]
+fn fold_sums_to_closed_form() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let n: u64 = 10_000;
+    
+    let sum = config.run_fold((1..=n).collect(), 0, |sum, num: u64| sum + num, |a, b| a + b);
+    
+    assert_eq!(sum, n * (n + 1) / 2);
+}
+
+#[test]
+fn fold_combines_in_chunk_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Concatenation is associative but not commutative
+    let result = config.run_fold((0..40).collect(), String::new(), |text, num: i64| text + &num.to_string(),
+        |a, b| a + &b);
+    
+    assert_eq!(result, (0..40).map(|num| num.to_string()).collect::<String>());
+}
+
+#[test]
+fn fold_on_sequential_path() {
+    let product = split_comp_fold(vec![1, 2, 3, 4], 1, |product, num: i64| product * num, |a, b| a * b);
+    
+    assert_eq!(product, 24);
+}
@@ -0,0 +1,84 @@
+use splitting_of_work::{split_comp_work_ref, split_comp_work_scoped, SplitConfig};
+
+// Item borrowing data from the stack of the caller
+struct Borrowed<'a> {
+    name: &'a str,
+    weight: &'a i64,
+}
+
+#[test]
+fn ref_on_sequential_path() {
+    let vector = vec![1, 2, 3, 4];
+    
+    let result = split_comp_work_ref(&vector, |num: &i64| num * 2);
+    
+    assert_eq!(result, vec![2, 4, 6, 8]);
+    assert_eq!(vector, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn ref_with_non_static_items() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let names: Vec<String> = (0..100).map(|num| format!("item {}", num)).collect();
+    let weights: Vec<i64> = (0..100).collect();
+    
+    let items: Vec<Borrowed> = names.iter().zip(&weights)
+        .map(|(name, weight)| Borrowed { name, weight })
+        .collect();
+    
+    let result = config.run_ref(&items, |item: &Borrowed| format!("{}: {}", item.name, item.weight));
+    
+    let result_for_check: Vec<String> = (0..100).map(|num| format!("item {}: {}", num, num)).collect();
+    
+    assert_eq!(result, result_for_check);
+}
+
+#[test]
+fn ref_with_borrowing_function() {
+    let config = SplitConfig::builder().threshold(2).max_threads(3).build().unwrap();
+    let offsets: Vec<i64> = (0..50).map(|num| num * 100).collect();
+    let indices: Vec<usize> = (0..50).rev().collect();
+    
+    let result = config.run_ref(&indices, |index: &usize| offsets[*index]);
+    
+    assert_eq!(result, offsets.iter().rev().copied().collect::<Vec<i64>>());
+}
+
+#[test]
+#[should_panic(expected = "Bad item")]
+fn ref_worker_panic_is_raised() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..100).collect();
+    
+    config.run_ref(&vector, |num: &i64| if *num == 50 { panic!("Bad item") } else { *num });
+}
+
+#[test]
+fn scoped_with_lookup_table() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let table: Vec<i64> = (0..1000).map(|num| num * 3).collect();
+    let lookup: &[i64] = &table;
+    
+    let result = config.run_scoped((0..1000).rev().collect(), |index: usize| lookup[index]);
+    
+    assert_eq!(result, table.iter().rev().copied().collect::<Vec<i64>>());
+}
+
+#[test]
+fn scoped_with_borrowed_items() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let names: Vec<String> = (0..100).map(|num| format!("item {}", num)).collect();
+    
+    let result = config.run_scoped(names.iter().map(String::as_str).collect(), |name: &str| name.len());
+    
+    assert_eq!(result, names.iter().map(String::len).collect::<Vec<usize>>());
+}
+
+#[test]
+fn scoped_on_sequential_path() {
+    let factor = 5;
+    
+    let result = split_comp_work_scoped(vec![1, 2, 3], |num: i64| num * factor);
+    
+    assert_eq!(result, vec![5, 10, 15]);
+}
@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use splitting_of_work::{split_comp_all, split_comp_any, split_find, SplitConfig};
+
+// Time of checking of one item by the slow predicate
+const ITEM_TIME: Duration = Duration::from_millis(1);
+
+#[test]
+fn witness_in_last_chunk_is_found() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert!(config.run_any((0..100).collect(), |num: &i64| *num == 99));
+    assert!(!config.run_any((0..100).collect(), |num: &i64| *num == 100));
+}
+
+#[test]
+fn counterexample_in_last_chunk_is_found() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert!(!config.run_all((0..100).collect(), |num: &i64| *num < 99));
+    assert!(config.run_all((0..100).collect(), |num: &i64| *num < 100));
+}
+
+#[test]
+fn first_chunk_witness_finishes_quickly() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_checked = AtomicUsize::new(0);
+    let start = Instant::now();
+    
+    let found = config.run_any((0..4000).collect(), |num: &i64| {
+        num_of_checked.fetch_add(1, Ordering::Relaxed);
+        thread::sleep(ITEM_TIME);
+        *num == 0
+    });
+    
+    assert!(found);
+    assert!(start.elapsed() < 200 * ITEM_TIME, "Took {:?}", start.elapsed());
+    assert!(num_of_checked.into_inner() < 400);
+}
+
+#[test]
+fn empty_vector_matches_sequential_semantics() {
+    assert!(!split_comp_any(Vec::<i64>::new(), |_| true));
+    assert!(split_comp_all(Vec::<i64>::new(), |_| false));
+}
+
+#[test]
+fn any_and_all_on_sequential_path() {
+    assert!(split_comp_any(vec![1, 2, 3], |num: &i64| *num == 2));
+    assert!(!split_comp_all(vec![1, 2, 3], |num: &i64| *num < 3));
+}
+
+#[test]
+fn find_first_without_match() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert_eq!(config.run_find_first((0..100).collect(), |num: &i64| *num < 0), None);
+    assert_eq!(config.run_find_first(Vec::new(), |num: &i64| *num < 0), None);
+}
+
+#[test]
+fn find_first_match_at_index_zero() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert_eq!(config.run_find_first((0..100).collect(), |num: &i64| *num % 10 == 0), Some((0, 0)));
+}
+
+#[test]
+fn find_first_returns_lowest_index_of_matches_in_several_chunks() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Later chunks find their matches first, while the first chunk is delayed
+    let found = config.run_find_first((0..400).collect(), |num: &i64| {
+        if *num < 100 {
+            thread::sleep(ITEM_TIME);
+        }
+        *num % 100 == 42
+    });
+    
+    assert_eq!(found, Some((42, 42)));
+}
+
+#[test]
+fn find_first_is_deterministic() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for _ in 0..50 {
+        let found = config.run_find_first((0..1000).collect(), |num: &i64| num % 3 == 2);
+        
+        assert_eq!(found, Some((2, 2)));
+    }
+}
+
+// Pseudo-random numbers of a linear congruential generator
+fn random_numbers(seed: u64, len: usize) -> Vec<u64> {
+    let mut state = seed;
+    
+    (0..len).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state >> 33
+    }).collect()
+}
+
+#[test]
+fn positions_match_iterator_on_random_corpus() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for seed in 0..200 {
+        let len = (seed as usize * 7) % 300;
+        let vector = random_numbers(seed, len);
+        let modulus = 1 + seed % 50;
+        let predicate = |num: &u64| num.is_multiple_of(modulus);
+        
+        assert_eq!(config.run_position(vector.clone(), predicate), vector.iter().position(predicate));
+        assert_eq!(config.run_rposition(vector.clone(), predicate), vector.iter().rposition(predicate));
+    }
+}
+
+#[test]
+fn rposition_match_at_last_index() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    assert_eq!(config.run_rposition((0..100).collect(), |num: &i64| *num % 10 == 9), Some(99));
+    assert_eq!(config.run_rposition((0..100).collect(), |num: &i64| *num < 0), None);
+}
+
+#[test]
+fn first_multiple_of_97_is_found() {
+    for max_threads in [1, 2, 4, 8] {
+        let config = SplitConfig::builder().max_threads(max_threads).build().unwrap();
+        
+        let found = config.run_find_first((1..=1000).collect(), |num: &u32| num.is_multiple_of(97));
+        
+        assert_eq!(found, Some((96, 97)), "Wrong match with {} threads", max_threads);
+    }
+    
+    assert_eq!(split_find((1..=1000).collect(), |num: &u32| num.is_multiple_of(97)), Some((96, 97)));
+}
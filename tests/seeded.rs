@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use splitting_of_work::{split_comp_work_seeded, SplitConfig};
+
+// Counting random points inside the unit circle, using a xorshift generator seeded with
+// the sub-seed of the item
+fn points_inside_circle(sub_seed: u64, num_of_points: u32) -> u32 {
+    let mut state = sub_seed | 1;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    };
+    
+    (0..num_of_points).filter(|_| {
+        let (x, y) = (next(), next());
+        x * x + y * y <= 1.0
+    }).count() as u32
+}
+
+#[test]
+fn results_dont_depend_on_number_of_threads() {
+    let one_thread = SplitConfig::builder().max_threads(1).build().unwrap();
+    let eight_threads = SplitConfig::builder().threshold(1).max_threads(8).build().unwrap();
+    
+    let vector = vec![1000; 200];
+    
+    let first = one_thread.run_seeded(vector.clone(), 2024, points_inside_circle);
+    let second = eight_threads.run_seeded(vector, 2024, points_inside_circle);
+    
+    assert_eq!(first, second);
+}
+
+#[test]
+fn results_are_reproducible() {
+    let first = split_comp_work_seeded(vec![500; 100], 7, points_inside_circle);
+    let second = split_comp_work_seeded(vec![500; 100], 7, points_inside_circle);
+    
+    assert_eq!(first, second);
+}
+
+#[test]
+fn sub_seeds_are_distinct() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let sub_seeds = config.run_seeded(vec![(); 10_000], 0, |sub_seed: u64, _| sub_seed);
+    let other_seeds = config.run_seeded(vec![(); 10_000], 1, |sub_seed: u64, _| sub_seed);
+    
+    assert_eq!(sub_seeds.iter().collect::<HashSet<_>>().len(), 10_000);
+    assert_ne!(sub_seeds, other_seeds);
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use splitting_of_work::{split_comp_work, split_comp_work_shared, SplitConfig};
+
+#[test]
+fn shared_on_sequential_path() {
+    let data: Arc<[i64]> = Arc::from(vec![1, 2, 3, 4]);
+    
+    let result = split_comp_work_shared(Arc::clone(&data), |num: &i64| num * 10);
+    
+    assert_eq!(result, vec![10, 20, 30, 40]);
+    assert_eq!(&*data, &[1, 2, 3, 4]);
+}
+
+#[test]
+fn shared_indices_line_up() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let data: Arc<[String]> = (0..1000).map(|num| num.to_string()).collect();
+    
+    let result = config.run_shared(Arc::clone(&data), |item: &String| item.parse::<i64>().unwrap());
+    
+    assert_eq!(result, (0..1000).collect::<Vec<i64>>());
+}
+
+#[test]
+fn shared_matches_owned() {
+    let config = SplitConfig::builder().threshold(3).max_threads(5).build().unwrap();
+    let vector: Vec<i64> = (0..257).collect();
+    let data: Arc<[i64]> = Arc::from(vector.clone());
+    
+    let result = config.run_shared(data, |num: &i64| num * num);
+    
+    assert_eq!(result, split_comp_work(vector, |num: i64| num * num));
+}
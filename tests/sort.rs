@@ -0,0 +1,46 @@
+use splitting_of_work::{split_comp_sort, split_comp_sort_by_key, SplitConfig};
+
+// Pseudo-random numbers of a linear congruential generator
+fn random_numbers(seed: u64, len: usize) -> Vec<u64> {
+    let mut state = seed;
+    
+    (0..len).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state >> 33
+    }).collect()
+}
+
+#[test]
+fn sort_of_shuffled_vector_matches_std() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector = random_numbers(7, 100_000);
+    
+    let mut expected = vector.clone();
+    expected.sort();
+    
+    assert_eq!(config.run_sort(vector), expected);
+}
+
+#[test]
+fn sort_by_key_is_stable() {
+    let config = SplitConfig::builder().max_threads(5).build().unwrap();
+    
+    // Few distinct keys, so many items have equal keys across chunks
+    let pairs: Vec<(u64, usize)> = random_numbers(11, 1000).into_iter()
+        .map(|num| num % 10)
+        .enumerate()
+        .map(|(index, key)| (key, index))
+        .collect();
+    
+    let mut expected = pairs.clone();
+    expected.sort_by_key(|pair| pair.0);
+    
+    assert_eq!(config.run_sort_by_key(pairs, |pair: &(u64, usize)| pair.0), expected);
+}
+
+#[test]
+fn sort_below_threshold_and_empty() {
+    assert_eq!(split_comp_sort(vec![3, 1, 2]), vec![1, 2, 3]);
+    assert_eq!(split_comp_sort(Vec::<i64>::new()), Vec::<i64>::new());
+    assert_eq!(split_comp_sort_by_key(vec![-3, 1, -2], |num: &i64| num.abs()), vec![1, -2, -3]);
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::thread::{self, ThreadId};
+
+use splitting_of_work::{SplitConfig, SplitError};
+
+// Stack size no system can provide, so spawning of every thread fails
+const HUGE_STACK_SIZE: usize = 1 << 60;
+
+fn failing_config() -> SplitConfig {
+    SplitConfig::builder().max_threads(4).stack_size(HUGE_STACK_SIZE).build().unwrap()
+}
+
+#[test]
+fn work_is_done_in_current_thread_if_threads_cannot_spawn() {
+    let config = failing_config();
+    let caller = thread::current().id();
+    
+    assert_eq!(config.run(0..100, |num: i64| num * 2), (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+    
+    let thread_ids: Vec<ThreadId> = config.run_ref(&(0..100).collect::<Vec<i64>>(), |_: &i64| thread::current().id());
+    
+    assert!(thread_ids.iter().all(|id| *id == caller));
+}
+
+#[test]
+fn all_paths_fall_back_to_current_thread() {
+    let config = failing_config();
+    let expected: Vec<i64> = (0..100).map(|num| num + 1).collect();
+    
+    assert_eq!(config.run_checked((0..100).collect(), |num: i64| num + 1).unwrap(), expected);
+    assert_eq!(config.run_with_progress((0..100).collect(), |num: i64| num + 1, |_, _| {}), expected);
+    assert_eq!(config.run_scoped((0..100).collect(), |num: i64| num + 1), expected);
+    assert_eq!(config.run_shared(Arc::from((0..100).collect::<Vec<i64>>()), |num: &i64| num + 1), expected);
+    assert_eq!(config.run_stream((0..100).collect(), |num: i64| num + 1).collect::<Vec<i64>>(), expected);
+    
+    let mut data: Vec<i64> = (0..100).collect();
+    
+    config.run_in_place(&mut data, |num: &mut i64| *num += 1);
+    
+    assert_eq!(data, expected);
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn panic_in_current_thread_is_resumed() {
+    failing_config().run(0..100, |num: i64| {
+        if num == 60 {
+            panic!("Bad value");
+        }
+        
+        num
+    });
+}
+
+#[test]
+fn bounded_channel_reports_spawn_failure() {
+    let config = SplitConfig::builder()
+        .max_threads(4)
+        .stack_size(HUGE_STACK_SIZE)
+        .channel_capacity(1)
+        .build()
+        .unwrap();
+    
+    let result = config.run_checked((0..100).collect(), |num: i64| num);
+    
+    assert!(matches!(result, Err(SplitError::SpawnFailed(_))));
+}
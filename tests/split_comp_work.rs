@@ -0,0 +1,225 @@
+use std::collections::VecDeque;
+use std::num::NonZeroU64;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use splitting_of_work::{split_comp_work, SplitConfig, THRESHOLD};
+
+// Base for checking number for evenness
+const EVEN_BASE: i64 = 2;
+
+// Example of client's function
+fn is_even(num: i64) -> bool {
+    num.checked_rem(EVEN_BASE).expect("Invalid number for checking for evenness") == 0
+}
+
+#[test]
+fn sequential_path() {
+    let vector = vec![1, 2, 3, 4];
+    
+    let result = split_comp_work(vector, is_even);
+    
+    let result_for_check = vec![false, true, false, true];
+    
+    assert_eq!(result, result_for_check);
+}
+
+#[test]
+fn threaded_path() {
+    let vector = vec![
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10,
+        11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        21, 22, 23, 24, 25, 26, 27, 28, 29, 30,
+        31, 32, 33, 34];
+    
+    let result = split_comp_work(vector, is_even);
+    
+    let result_for_check = vec![
+        false, true, false, true, false, true, false, true, false, true,
+        false, true, false, true, false, true, false, true, false, true,
+        false, true, false, true, false, true, false, true, false, true,
+        false, true, false, true];
+    
+    assert_eq!(result, result_for_check);
+}
+
+#[test]
+fn closure_on_sequential_path() {
+    let factor: i64 = 3;
+    let vector = vec![1, 2, 3, 4];
+    
+    let result = split_comp_work(vector, move |num: i64| num * factor);
+    
+    assert_eq!(result, vec![3, 6, 9, 12]);
+}
+
+#[test]
+fn closure_on_threaded_path() {
+    let factor: i64 = 3;
+    let vector: Vec<i64> = (1..=34).collect();
+    
+    let result = split_comp_work(vector, move |num: i64| num * factor);
+    
+    let result_for_check: Vec<i64> = (1..=34).map(|num| num * 3).collect();
+    
+    assert_eq!(result, result_for_check);
+}
+
+#[test]
+fn empty_vector() {
+    let vector: Vec<i64> = Vec::new();
+    
+    let result = split_comp_work(vector, is_even);
+    
+    assert!(result.is_empty());
+}
+
+#[test]
+fn empty_vector_spawns_no_threads() {
+    let config = SplitConfig::builder().threshold(1).max_threads(4).build().unwrap();
+    
+    let result: Vec<i64> = config.run(Vec::<i64>::new(), |_| -> i64 { panic!("No items to process") });
+    let checked: Vec<i64> = config.run_checked(Vec::<i64>::new(), |num: i64| num).unwrap();
+    
+    assert!(result.is_empty());
+    assert!(checked.is_empty());
+}
+
+#[test]
+fn single_element() {
+    let vector = vec![7];
+    
+    let result = split_comp_work(vector, is_even);
+    
+    assert_eq!(result, vec![false]);
+}
+
+#[test]
+fn threshold_boundary() {
+    let vector: Vec<i64> = (0..THRESHOLD as i64).collect();
+    
+    let result = split_comp_work(vector, is_even);
+    
+    let result_for_check: Vec<bool> = (0..THRESHOLD as i64).map(is_even).collect();
+    
+    assert_eq!(result, result_for_check);
+}
+
+#[test]
+fn result_without_default() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<u64> = (1..=100).collect();
+    
+    let result = config.run(vector, |num: u64| NonZeroU64::new(num).unwrap());
+    
+    let result_for_check: Vec<NonZeroU64> = (1..=100).map(|num| NonZeroU64::new(num).unwrap()).collect();
+    
+    assert_eq!(result, result_for_check);
+}
+
+// Item which deliberately doesn't implement `Clone`
+struct NonCloneable {
+    value: i64,
+}
+
+#[test]
+fn input_without_clone_on_sequential_path() {
+    let vector: Vec<NonCloneable> = (0..4).map(|value| NonCloneable { value }).collect();
+    
+    let result = split_comp_work(vector, |item: NonCloneable| item.value * 2);
+    
+    assert_eq!(result, vec![0, 2, 4, 6]);
+}
+
+#[test]
+fn input_without_clone_on_threaded_path() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<NonCloneable> = (0..100).map(|value| NonCloneable { value }).collect();
+    
+    let result = config.run(vector, |item: NonCloneable| item.value * 2);
+    
+    assert_eq!(result, (0..100).map(|value| value * 2).collect::<Vec<i64>>());
+}
+
+// Result which deliberately implements neither `Default` nor `Clone`
+#[derive(Debug, PartialEq)]
+struct Labelled {
+    label: String,
+}
+
+#[test]
+fn result_without_default_and_clone_keeps_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let vector: Vec<i64> = (0..1000).collect();
+    
+    let result = config.run(vector, |num: i64| Labelled { label: format!("item {}", num) });
+    
+    let result_for_check: Vec<Labelled> = (0..1000).map(|num| Labelled { label: format!("item {}", num) }).collect();
+    
+    assert_eq!(result, result_for_check);
+}
+
+#[test]
+fn array_input() {
+    let result = split_comp_work([1, 2, 3, 4], is_even);
+    
+    assert_eq!(result, vec![false, true, false, true]);
+}
+
+#[test]
+fn deque_input() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let deque: VecDeque<i64> = (1..=34).collect();
+    
+    let result = config.run(deque, is_even);
+    
+    assert_eq!(result, (1..=34).map(is_even).collect::<Vec<bool>>());
+}
+
+#[test]
+fn iterator_input_is_split() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run((1..=34).map(|num| num * 3), is_even);
+    
+    assert_eq!(result, (1..=34).map(|num| is_even(num * 3)).collect::<Vec<bool>>());
+}
+
+// Example of client's item which counts its clones
+struct CountedClones {
+    value: i64,
+    num_of_clones: Arc<AtomicUsize>,
+}
+
+impl Clone for CountedClones {
+    fn clone(&self) -> Self {
+        self.num_of_clones.fetch_add(1, Ordering::Relaxed);
+        
+        CountedClones { value: self.value, num_of_clones: Arc::clone(&self.num_of_clones) }
+    }
+}
+
+#[test]
+fn items_are_not_cloned() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_clones = Arc::new(AtomicUsize::new(0));
+    
+    for len in [1, 2, 100] {
+        let vector: Vec<CountedClones> = (0..len)
+            .map(|value| CountedClones { value, num_of_clones: Arc::clone(&num_of_clones) })
+            .collect();
+        
+        let result = config.run(vector, |item: CountedClones| item.value * 2);
+        
+        assert_eq!(result, (0..len).map(|value| value * 2).collect::<Vec<i64>>());
+    }
+    
+    assert_eq!(num_of_clones.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn singletons_in_hot_loop() {
+    for num in 0..10_000 {
+        assert_eq!(split_comp_work(vec![num], is_even), vec![num % 2 == 0]);
+    }
+}
@@ -0,0 +1,69 @@
+use std::thread;
+use std::time::Duration;
+
+use splitting_of_work::{split_comp_work_timed, split_comp_work_with_stats, SplitConfig, WorkStats};
+
+#[test]
+fn stats_of_threaded_work() {
+    let config = SplitConfig::builder().max_threads(8).build().unwrap();
+    
+    let (result, stats) = config.run_with_stats((1..=34).collect(), |num: i64| num * 2);
+    
+    assert_eq!(result, (1..=34).map(|num| num * 2).collect::<Vec<i64>>());
+    assert_eq!(stats, WorkStats { num_threads: 5, items_per_thread: 7, sequential: false });
+}
+
+#[test]
+fn stats_are_capped_by_max_threads() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let (_, stats) = config.run_with_stats((0..100).collect(), |num: i64| num);
+    
+    assert_eq!(stats, WorkStats { num_threads: 4, items_per_thread: 25, sequential: false });
+}
+
+#[test]
+fn stats_of_sequential_work() {
+    let (result, stats) = split_comp_work_with_stats(vec![1, 2, 3], |num: i64| num + 1);
+    
+    assert_eq!(result, vec![2, 3, 4]);
+    assert_eq!(stats, WorkStats { num_threads: 1, items_per_thread: 3, sequential: true });
+}
+
+#[test]
+fn one_duration_per_thread() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    for len in [0, 3, 8, 20, 100] {
+        let (result, durations) = config.run_timed((0..len).collect(), |num: i64| num + 1);
+        let (_, stats) = config.run_with_stats((0..len).collect(), |num: i64| num + 1);
+        
+        assert_eq!(result, (1..=len).collect::<Vec<i64>>());
+        assert_eq!(durations.len(), stats.num_threads, "Wrong number of durations for {} items", len);
+    }
+}
+
+#[test]
+fn slow_chunk_takes_longer() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Only the items of the last chunk are slow
+    let (_, durations) = config.run_timed((0..40).collect(), |num: u64| {
+        if num >= 30 {
+            thread::sleep(Duration::from_millis(5));
+        }
+        num
+    });
+    
+    assert_eq!(durations.len(), 4);
+    assert!(durations[3] >= Duration::from_millis(50));
+    assert!(durations[..3].iter().all(|duration| *duration < durations[3]));
+}
+
+#[test]
+fn timed_work_in_current_thread() {
+    let (result, durations) = split_comp_work_timed(vec![1, 2, 3], |num: i64| num * 2);
+    
+    assert_eq!(result, vec![2, 4, 6]);
+    assert_eq!(durations.len(), 1);
+}
@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use splitting_of_work::{split_comp_work_ordered_stream, split_comp_work_stream, SplitConfig};
+
+// Index from which items are slow, it's the start of the last chunk of four
+const FIRST_SLOW_INDEX: i64 = 30;
+
+// Time of computational work on one slow item
+const SLOW_ITEM_TIME: Duration = Duration::from_millis(50);
+
+// Example of client's function which is slow on the last chunk
+fn slow_on_last_chunk(num: i64) -> i64 {
+    if num >= FIRST_SLOW_INDEX {
+        thread::sleep(SLOW_ITEM_TIME);
+    }
+    
+    num * 2
+}
+
+#[test]
+fn stream_yields_results_in_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result: Vec<i64> = config.run_stream((0..40).collect(), slow_on_last_chunk).collect();
+    
+    assert_eq!(result, (0..40).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+fn early_results_come_before_late_chunks_finish() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let start = Instant::now();
+    
+    let mut stream = config.run_stream((0..40).collect(), slow_on_last_chunk);
+    
+    let early: Vec<i64> = stream.by_ref().take(FIRST_SLOW_INDEX as usize).collect();
+    let early_elapsed = start.elapsed();
+    
+    assert_eq!(early, (0..FIRST_SLOW_INDEX).map(|num| num * 2).collect::<Vec<i64>>());
+    assert!(early_elapsed < 5 * SLOW_ITEM_TIME, "Early results took {:?}", early_elapsed);
+    
+    assert_eq!(stream.size_hint(), (10, Some(10)));
+    assert_eq!(stream.collect::<Vec<i64>>(), (FIRST_SLOW_INDEX..40).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn worker_panic_is_raised_by_stream() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let stream = config.run_stream((0..40).collect(), |num: i64| {
+        if num == 25 {
+            panic!("Bad value");
+        }
+        
+        num
+    });
+    
+    stream.for_each(drop);
+}
+
+#[test]
+fn dropped_stream_stops_threads() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_calls = Arc::new(AtomicUsize::new(0));
+    let num_of_calls_copy = Arc::clone(&num_of_calls);
+    
+    let mut stream = config.run_stream((0..400).collect(), move |num: i64| {
+        num_of_calls_copy.fetch_add(1, Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(1));
+        num
+    });
+    
+    assert_eq!(stream.next(), Some(0));
+    
+    drop(stream);
+    thread::sleep(Duration::from_millis(50));
+    
+    assert!(num_of_calls.load(Ordering::Relaxed) < 400);
+}
+
+#[test]
+fn stream_on_sequential_path() {
+    let result: Vec<i64> = split_comp_work_stream(vec![1, 2, 3], |num: i64| num + 1).collect();
+    
+    assert_eq!(result, vec![2, 3, 4]);
+}
+
+#[test]
+fn reversed_arrival_is_yielded_in_order() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Latency falls with the index, so later chunks finish first
+    let stream = config.run_stream((0..40).collect(), |num: i64| {
+        thread::sleep(Duration::from_millis(2 * (40 - num as u64)));
+        num
+    });
+    
+    assert_eq!(stream.collect::<Vec<i64>>(), (0..40).collect::<Vec<i64>>());
+}
+
+#[test]
+fn ordered_stream_yields_in_input_order() {
+    let stream = split_comp_work_ordered_stream((0..1000).collect(), |num: u64| num * 2);
+    
+    assert_eq!(stream.collect::<Vec<u64>>(), (0..1000).map(|num| num * 2).collect::<Vec<u64>>());
+}
@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+use splitting_of_work::{split_comp_work_with_threshold, SplitConfig};
+
+// Maximum number of threads, which doesn't depend on the hardware
+const MAX_THREADS: usize = 4;
+
+// Runs the work and returns results together with ids of threads that did it
+fn run_with_threshold(len: usize, threshold: usize) -> (Vec<usize>, Vec<ThreadId>) {
+    let thread_ids = Arc::new(Mutex::new(Vec::new()));
+    let thread_ids_copy = Arc::clone(&thread_ids);
+    
+    let vector: Vec<usize> = (0..len).collect();
+    
+    let config = SplitConfig::builder().threshold(threshold).max_threads(MAX_THREADS).build().unwrap();
+    
+    let result = config.run(vector, move |num: usize| {
+        thread_ids_copy.lock().unwrap().push(thread::current().id());
+        num * 2
+    });
+    
+    let thread_ids = thread_ids.lock().unwrap().clone();
+    
+    (result, thread_ids)
+}
+
+#[test]
+fn length_equal_to_threshold_is_split() {
+    let (result, thread_ids) = run_with_threshold(16, 16);
+    
+    assert_eq!(result, (0..16).map(|num| num * 2).collect::<Vec<usize>>());
+    assert!(thread_ids.iter().all(|id| *id != thread::current().id()));
+}
+
+#[test]
+fn length_below_threshold_is_sequential() {
+    let (result, thread_ids) = run_with_threshold(15, 16);
+    
+    assert_eq!(result, (0..15).map(|num| num * 2).collect::<Vec<usize>>());
+    assert!(thread_ids.iter().all(|id| *id == thread::current().id()));
+}
+
+#[test]
+fn threshold_larger_than_length_is_sequential() {
+    let (result, thread_ids) = run_with_threshold(100, 1000);
+    
+    assert_eq!(result, (0..100).map(|num| num * 2).collect::<Vec<usize>>());
+    assert!(thread_ids.iter().all(|id| *id == thread::current().id()));
+}
+
+#[test]
+fn threshold_of_one_always_splits() {
+    let (result, thread_ids) = run_with_threshold(2, 1);
+    
+    assert_eq!(result, vec![0, 2]);
+    assert!(thread_ids.iter().all(|id| *id != thread::current().id()));
+}
+
+#[test]
+fn threshold_is_passed_through() {
+    let vector: Vec<usize> = (0..100).collect();
+    
+    let result = split_comp_work_with_threshold(vector, |num: usize| num * 2, 10);
+    
+    assert_eq!(result, (0..100).map(|num| num * 2).collect::<Vec<usize>>());
+}
+
+#[test]
+#[should_panic(expected = "Threshold must be at least one item")]
+fn threshold_of_zero_is_rejected() {
+    split_comp_work_with_threshold(vec![1, 2], |num: usize| num * 2, 0);
+}
+
+#[test]
+fn threshold_of_one_splits_aggressively() {
+    let (result, thread_ids) = run_with_threshold(34, 1);
+    
+    assert_eq!(result, (0..34).map(|num| num * 2).collect::<Vec<usize>>());
+    assert!(thread_ids.iter().all(|id| *id != thread::current().id()));
+}
+
+#[test]
+fn single_item_is_sequential() {
+    let (result, thread_ids) = run_with_threshold(1, 1);
+    
+    assert_eq!(result, vec![0]);
+    assert_eq!(thread_ids, vec![thread::current().id()]);
+}
@@ -0,0 +1,112 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use splitting_of_work::{split_comp_work_timeout, split_comp_work_timeout_partial, SplitConfig, SplitError};
+
+// Time of computational work on one item of the slow function
+const ITEM_TIME: Duration = Duration::from_millis(20);
+
+// Timeout of the slow work
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+// Example of client's function which takes a lot of time
+fn slow_double(num: i64) -> i64 {
+    thread::sleep(ITEM_TIME);
+    num * 2
+}
+
+#[test]
+fn work_within_timeout_returns_results() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let result = config.run_timeout((0..100).collect(), |num: i64| num * 2, Duration::from_secs(5));
+    
+    assert_eq!(result.unwrap(), (0..100).map(|num| num * 2).collect::<Vec<i64>>());
+}
+
+#[test]
+fn slow_work_returns_near_deadline() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let start = Instant::now();
+    
+    let result = config.run_timeout((0..100).collect(), slow_double, TIMEOUT);
+    
+    let elapsed = start.elapsed();
+    
+    assert!(matches!(result, Err(SplitError::TimedOut { completed }) if completed < 100));
+    assert!(elapsed >= TIMEOUT);
+    assert!(elapsed < TIMEOUT + 5 * ITEM_TIME, "Returned after {:?}", elapsed);
+}
+
+#[test]
+fn threads_stop_after_timeout() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let num_of_calls = Arc::new(AtomicUsize::new(0));
+    let num_of_calls_copy = Arc::clone(&num_of_calls);
+    
+    let result = config.run_timeout((0..100).collect(), move |num: i64| {
+        num_of_calls_copy.fetch_add(1, Ordering::Relaxed);
+        slow_double(num)
+    }, TIMEOUT);
+    
+    assert!(result.is_err());
+    
+    // Every thread starts at most one more item after the timeout
+    let num_after_timeout = num_of_calls.load(Ordering::Relaxed);
+    thread::sleep(5 * ITEM_TIME);
+    
+    assert!(num_of_calls.load(Ordering::Relaxed) <= num_after_timeout + 4);
+}
+
+#[test]
+fn sequential_work_times_out() {
+    let result = split_comp_work_timeout(vec![1, 2, 3, 4, 5, 6, 7], slow_double, 2 * ITEM_TIME);
+    
+    assert!(matches!(result, Err(SplitError::TimedOut { completed }) if completed < 7));
+}
+
+#[test]
+fn sequential_item_blocks_past_deadline() {
+    let start = Instant::now();
+    
+    // Deadline passes during the only item, which can't be interrupted
+    let result = split_comp_work_timeout(vec![1], |num: i64| slow_double(slow_double(num)), ITEM_TIME);
+    
+    assert_eq!(result.unwrap(), vec![4]);
+    assert!(start.elapsed() >= 2 * ITEM_TIME);
+    
+    let result = split_comp_work_timeout_partial(vec![1, 2], slow_double, ITEM_TIME / 2);
+    
+    assert_eq!(result.completed, vec![(0, 2)]);
+    assert_eq!(result.missing, vec![1]);
+}
+
+#[test]
+fn partial_results_are_kept() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    // Items of the last chunk are stuck for longer than the timeout
+    let result = config.run_timeout_partial((0..40).collect(), |num: i64| {
+        if num >= 30 {
+            thread::sleep(2 * TIMEOUT);
+        }
+        
+        num * 2
+    }, TIMEOUT);
+    
+    let mut completed = result.completed;
+    completed.sort_by_key(|(index, _)| *index);
+    
+    assert_eq!(completed, (0..30).map(|num| (num as usize, num * 2)).collect::<Vec<(usize, i64)>>());
+    assert_eq!(result.missing, (30..40).collect::<Vec<usize>>());
+}
+
+#[test]
+fn partial_results_within_timeout_are_complete() {
+    let result = split_comp_work_timeout_partial(vec![1, 2, 3], |num: i64| num + 1, TIMEOUT);
+    
+    assert_eq!(result.completed, vec![(0, 2), (1, 3), (2, 4)]);
+    assert!(result.missing.is_empty());
+}
@@ -0,0 +1,89 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use splitting_of_work::{split_comp_work, split_comp_work_unordered, split_comp_work_unordered_iter, SplitConfig};
+
+#[test]
+fn sorted_results_match_ordered_api() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let mut result = config.run_unordered((0..100).collect(), |num: i64| num * num);
+    
+    result.sort_by_key(|(index, _)| *index);
+    
+    let values: Vec<i64> = result.iter().map(|(_, value)| *value).collect();
+    
+    assert_eq!(result.len(), 100);
+    assert!(result.iter().enumerate().all(|(position, (index, _))| position == *index));
+    assert_eq!(values, config.run(0..100, |num: i64| num * num));
+}
+
+#[test]
+fn sequential_path_emits_pairs_in_order() {
+    let result = split_comp_work_unordered(vec![5, 6, 7], |num: i64| num + 1);
+    
+    assert_eq!(result, vec![(0, 6), (1, 7), (2, 8)]);
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn worker_panic_is_raised() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    config.run_unordered((0..100).collect(), |num: i64| {
+        if num == 50 {
+            panic!("Bad value");
+        }
+        
+        num
+    });
+}
+
+#[test]
+fn sorted_lazy_results_reproduce_split_comp_work() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let mut result: Vec<_> = config.run_unordered_iter((0..100).collect(), |num: i64| num * 3).collect();
+    
+    result.sort_by_key(|(index, _)| *index);
+    
+    let values: Vec<i64> = result.into_iter().map(|(_, value)| value).collect();
+    
+    assert_eq!(values, split_comp_work((0..100).collect::<Vec<i64>>(), |num: i64| num * 3));
+}
+
+#[test]
+fn lazy_results_arrive_before_all_work_is_done() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let start = Instant::now();
+    
+    let mut results = config.run_unordered_iter((0..40).collect(), |num: i64| {
+        thread::sleep(Duration::from_millis(10));
+        num
+    });
+    
+    assert!(results.next().is_some());
+    assert!(start.elapsed() < Duration::from_millis(100), "Took {:?}", start.elapsed());
+    assert_eq!(results.count(), 39);
+}
+
+#[test]
+fn lazy_sequential_path_emits_pairs_in_order() {
+    let result: Vec<_> = split_comp_work_unordered_iter(vec![5, 6, 7], |num: i64| num + 1).collect();
+    
+    assert_eq!(result, vec![(0, 6), (1, 7), (2, 8)]);
+}
+
+#[test]
+#[should_panic(expected = "Bad value")]
+fn lazy_worker_panic_is_raised() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    config.run_unordered_iter((0..100).collect(), |num: i64| {
+        if num == 50 {
+            panic!("Bad value");
+        }
+        
+        num
+    }).for_each(drop);
+}
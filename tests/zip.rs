@@ -0,0 +1,72 @@
+use splitting_of_work::{split_comp_unzip, split_comp_zip_map, split_zip_map, SplitConfig, SplitError};
+
+#[test]
+fn add_vectors_elementwise() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let a: Vec<i64> = (0..100).collect();
+    let b: Vec<i64> = (0..100).map(|num| num * 10).collect();
+    
+    let result = config.run_zip_map(a, b, |a: i64, b: i64| a + b);
+    
+    assert_eq!(result.unwrap(), (0..100).map(|num| num * 11).collect::<Vec<i64>>());
+}
+
+#[test]
+fn zip_on_sequential_path() {
+    let result = split_zip_map(vec!["a", "b"], vec![1, 2], |a: &str, b: usize| a.repeat(b));
+    
+    assert_eq!(result.unwrap(), vec!["a".to_string(), "bb".to_string()]);
+}
+
+#[test]
+fn different_lengths_are_rejected() {
+    let result = split_zip_map(vec![1, 2, 3], vec![1, 2], |a: i64, b: i64| a + b);
+    
+    assert!(matches!(result, Err(SplitError::LengthMismatch { left: 3, right: 2 })));
+}
+
+#[test]
+fn multiply_large_vectors_elementwise() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    let values: Vec<f64> = (0..10_000).map(|num| num as f64).collect();
+    let weights: Vec<f64> = (0..10_000).map(|num| (num % 7) as f64 * 0.5).collect();
+    
+    let expected: Vec<f64> = values.iter().zip(&weights).map(|(value, weight)| value * weight).collect();
+    
+    assert_eq!(config.run_zip_map(values, weights, |value: f64, weight: f64| value * weight).unwrap(), expected);
+}
+
+#[test]
+fn unzip_keeps_sides_aligned() {
+    let config = SplitConfig::builder().max_threads(4).build().unwrap();
+    
+    let (nums, doubles) = config.run_unzip((0..1000).collect(), |num: i64| (num, num * 2));
+    
+    assert_eq!(nums, (0..1000).collect::<Vec<i64>>());
+    assert!(nums.iter().zip(&doubles).all(|(num, double)| num * 2 == *double));
+}
+
+#[test]
+fn unzip_sequential_path_matches_threaded() {
+    let config = SplitConfig::builder().threshold(1).max_threads(4).build().unwrap();
+    let function = |num: i64| (num.to_string(), num % 3);
+    
+    let sequential = split_comp_unzip(vec![4, 5, 6], function);
+    let threaded = config.run_unzip(vec![4, 5, 6], function);
+    
+    assert_eq!(sequential, (vec!["4".to_string(), "5".to_string(), "6".to_string()], vec![1, 2, 0]));
+    assert_eq!(threaded, sequential);
+    assert_eq!(split_comp_unzip(Vec::<i64>::new(), function), (Vec::new(), Vec::new()));
+}
+
+#[test]
+fn split_comp_zip_map_matches_split_zip_map() {
+    let a: Vec<i64> = (0..1000).collect();
+    let b: Vec<i64> = (0..1000).rev().collect();
+    
+    let expected = split_zip_map(a.clone(), b.clone(), |a: i64, b: i64| a - b).unwrap();
+    
+    assert_eq!(split_comp_zip_map(a, b, |a: i64, b: i64| a - b).unwrap(), expected);
+    assert!(matches!(split_comp_zip_map(vec![1], Vec::<i64>::new(), |a: i64, b: i64| a + b),
+        Err(SplitError::LengthMismatch { left: 1, right: 0 })));
+}